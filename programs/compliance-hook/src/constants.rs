@@ -0,0 +1,5 @@
+pub const SEED_COMPLIANCE_CONFIG_ACCOUNT: &[u8] = b"compliance_config";
+pub const SEED_INVESTOR_ENTRY_ACCOUNT: &[u8] = b"investor_entry";
+
+// Seed the token program uses to locate the extra accounts of a transfer hook
+pub const SEED_EXTRA_ACCOUNT_METAS_ACCOUNT: &[u8] = b"extra-account-metas";
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The destination wallet is not registered as an investor for this mint.")]
+    InvestorNotRegistered,
+
+    #[msg("The hook can only be invoked during a token transfer.")]
+    NotTransferring,
+
+    #[msg("The mint does not use this program as its transfer hook.")]
+    InvalidTransferHook,
+
+    #[msg("Only the mint authority can set up the registry.")]
+    UnauthorizedMintAuthority,
+
+    #[msg("The transfer hook of the mint must not have an authority.")]
+    MutableTransferHook,
+
+    #[msg("The destination token account must have an immutable owner.")]
+    MutableDestinationOwner,
+}
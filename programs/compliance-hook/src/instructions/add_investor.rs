@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ComplianceConfig, InvestorEntry};
+use crate::{SEED_COMPLIANCE_CONFIG_ACCOUNT, SEED_INVESTOR_ENTRY_ACCOUNT};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddInvestor<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Mint account is validated through compliance_config has_one constraint
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        has_one = mint,
+        has_one = authority,
+        seeds = [SEED_COMPLIANCE_CONFIG_ACCOUNT, mint.key().as_ref()],
+        bump = compliance_config.bump,
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + InvestorEntry::INIT_SPACE,
+        seeds = [SEED_INVESTOR_ENTRY_ACCOUNT, mint.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub investor_entry: Account<'info, InvestorEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_investor(ctx: Context<AddInvestor>, wallet: Pubkey) -> Result<()> {
+    let investor_entry = &mut ctx.accounts.investor_entry;
+    investor_entry.mint = ctx.accounts.mint.key();
+    investor_entry.wallet = wallet;
+    investor_entry.bump = ctx.bumps.investor_entry;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook;
+use anchor_spl::token_interface::{get_mint_extension_data, Mint};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::error::ErrorCode;
+use crate::state::ComplianceConfig;
+use crate::{
+    SEED_COMPLIANCE_CONFIG_ACCOUNT, SEED_EXTRA_ACCOUNT_METAS_ACCOUNT, SEED_INVESTOR_ENTRY_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// Must still be the mint authority, so only the mint's creator sets up its registry
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(extra_account_metas()?.len())?,
+        seeds = [SEED_EXTRA_ACCOUNT_METAS_ACCOUNT, mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: TLV data is written by the handler
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComplianceConfig::INIT_SPACE,
+        seeds = [SEED_COMPLIANCE_CONFIG_ACCOUNT, mint.key().as_ref()],
+        bump
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The transfer hook needs the investor entry of the destination token account owner
+pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+    Ok(vec![ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: SEED_INVESTOR_ENTRY_ACCOUNT.to_vec(),
+            },
+            // Mint
+            Seed::AccountKey { index: 1 },
+            // Owner field of the destination token account
+            Seed::AccountData {
+                account_index: 2,
+                data_index: 32,
+                length: 32,
+            },
+        ],
+        false,
+        false,
+    )?])
+}
+
+/// `authority` manages the investor registry, e.g. a PDA of the program that administers the mint
+pub fn handle_initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaList>,
+    authority: Pubkey,
+) -> Result<()> {
+    let transfer_hook =
+        get_mint_extension_data::<TransferHook>(&ctx.accounts.mint.to_account_info())?;
+
+    let hook_program_id: Option<Pubkey> = transfer_hook.program_id.into();
+    require!(
+        hook_program_id == Some(crate::ID),
+        ErrorCode::InvalidTransferHook
+    );
+
+    // The hook itself has no authority, so nobody can point the mint at another program later
+    let hook_authority: Option<Pubkey> = transfer_hook.authority.into();
+    require!(hook_authority.is_none(), ErrorCode::MutableTransferHook);
+
+    // Only the mint's creator may decide who manages its registry
+    require!(
+        ctx.accounts.mint.mint_authority == COption::Some(ctx.accounts.payer.key()),
+        ErrorCode::UnauthorizedMintAuthority
+    );
+
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas()?)?;
+
+    let compliance_config = &mut ctx.accounts.compliance_config;
+    compliance_config.mint = ctx.accounts.mint.key();
+    compliance_config.authority = authority;
+    compliance_config.bump = ctx.bumps.compliance_config;
+
+    Ok(())
+}
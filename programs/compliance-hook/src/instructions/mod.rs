@@ -0,0 +1,11 @@
+pub mod initialize_extra_account_meta_list;
+pub use initialize_extra_account_meta_list::*;
+
+pub mod add_investor;
+pub use add_investor::*;
+
+pub mod remove_investor;
+pub use remove_investor::*;
+
+pub mod transfer_hook;
+pub use transfer_hook::*;
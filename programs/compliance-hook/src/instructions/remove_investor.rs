@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ComplianceConfig, InvestorEntry};
+use crate::{SEED_COMPLIANCE_CONFIG_ACCOUNT, SEED_INVESTOR_ENTRY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct RemoveInvestor<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Any account chosen by the authority to receive the rent
+    #[account(mut)]
+    pub rent_receiver: AccountInfo<'info>,
+
+    /// CHECK: Mint account is validated through compliance_config has_one constraint
+    pub mint: AccountInfo<'info>,
+
+    #[account(
+        has_one = mint,
+        has_one = authority,
+        seeds = [SEED_COMPLIANCE_CONFIG_ACCOUNT, mint.key().as_ref()],
+        bump = compliance_config.bump,
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    #[account(
+        mut,
+        close = rent_receiver,
+        has_one = mint,
+        seeds = [SEED_INVESTOR_ENTRY_ACCOUNT, mint.key().as_ref(), investor_entry.wallet.as_ref()],
+        bump = investor_entry.bump,
+    )]
+    pub investor_entry: Account<'info, InvestorEntry>,
+}
+
+/// Shares already held stay with the wallet, it just can't receive any more
+pub fn handle_remove_investor(_ctx: Context<RemoveInvestor>) -> Result<()> {
+    Ok(())
+}
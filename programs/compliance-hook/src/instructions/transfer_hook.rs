@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    immutable_owner::ImmutableOwner, transfer_hook::TransferHookAccount, BaseStateWithExtensions,
+    StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Account as SplTokenAccount;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::ErrorCode;
+use crate::{SEED_EXTRA_ACCOUNT_METAS_ACCOUNT, SEED_INVESTOR_ENTRY_ACCOUNT};
+
+// Account order is fixed by the transfer hook interface
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: source token account owner or delegate, validated by the token program
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_EXTRA_ACCOUNT_METAS_ACCOUNT, mint.key().as_ref()],
+        bump
+    )]
+    /// CHECK: validated by seeds
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_INVESTOR_ENTRY_ACCOUNT, mint.key().as_ref(), destination_token.owner.as_ref()],
+        bump
+    )]
+    /// CHECK: may not exist, the handler rejects the transfer in that case
+    pub investor_entry: UncheckedAccount<'info>,
+}
+
+pub fn handle_transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+    // Without this anyone could call the hook directly and pretend a transfer happened
+    let source_info = ctx.accounts.source_token.to_account_info();
+    let source_data = source_info.try_borrow_data()?;
+    let source_account = StateWithExtensions::<SplTokenAccount>::unpack(&source_data)?;
+    let transfer_hook_account = source_account.get_extension::<TransferHookAccount>()?;
+    require!(
+        bool::from(transfer_hook_account.transferring),
+        ErrorCode::NotTransferring
+    );
+
+    // The registry check below only holds if the destination can't be handed to another wallet
+    // afterwards
+    let destination_info = ctx.accounts.destination_token.to_account_info();
+    let destination_data = destination_info.try_borrow_data()?;
+    let destination_account = StateWithExtensions::<SplTokenAccount>::unpack(&destination_data)?;
+    require!(
+        destination_account
+            .get_extension::<ImmutableOwner>()
+            .is_ok(),
+        ErrorCode::MutableDestinationOwner
+    );
+
+    let investor_entry = &ctx.accounts.investor_entry;
+    require!(
+        investor_entry.owner == &crate::ID && !investor_entry.data_is_empty(),
+        ErrorCode::InvestorNotRegistered
+    );
+
+    Ok(())
+}
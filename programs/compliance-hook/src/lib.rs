@@ -0,0 +1,39 @@
+pub mod constants;
+pub mod error;
+pub mod instructions;
+pub mod state;
+
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+pub use constants::*;
+pub use instructions::*;
+
+declare_id!("8TBXX336g6tdG7GUke8ZMroVzkq1XDtopWTg8DxTqosZ");
+
+#[program]
+pub mod compliance_hook {
+
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        handle_initialize_extra_account_meta_list(ctx, authority)
+    }
+
+    pub fn add_investor(ctx: Context<AddInvestor>, wallet: Pubkey) -> Result<()> {
+        handle_add_investor(ctx, wallet)
+    }
+
+    pub fn remove_investor(ctx: Context<RemoveInvestor>) -> Result<()> {
+        handle_remove_investor(ctx)
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        handle_transfer_hook(ctx, amount)
+    }
+}
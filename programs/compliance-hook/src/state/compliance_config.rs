@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceConfig {
+    pub mint: Pubkey,
+
+    /// Compliance officer allowed to manage the investor registry of the mint
+    pub authority: Pubkey,
+
+    pub bump: u8,
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// Its existence allows `wallet` to receive shares of `mint`
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorEntry {
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+    pub bump: u8,
+}
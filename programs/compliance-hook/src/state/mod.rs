@@ -0,0 +1,5 @@
+pub mod compliance_config;
+pub use compliance_config::*;
+
+pub mod investor_entry;
+pub use investor_entry::*;
@@ -1,13 +1,76 @@
 pub const SEED_VAULT_OWNER_ACCOUNT: &[u8] = b"vault_onwer";
 pub const SEED_VAULT_AUTHORITY_ACCOUNT: &[u8] = b"vault_authority";
 pub const SEED_STATE_ACCOUNT: &[u8] = b"asset_state";
+pub const SEED_ASSET_CONFIG_ACCOUNT: &[u8] = b"asset_config";
+pub const SEED_FREEZE_AUTHORITY_ACCOUNT: &[u8] = b"freeze_authority";
+pub const SEED_COMPLIANCE_AUTHORITY_ACCOUNT: &[u8] = b"compliance_authority";
+pub const SEED_PORTFOLIO_ACCOUNT: &[u8] = b"portfolio";
+pub const SEED_SHARE_SPLIT_ACCOUNT: &[u8] = b"share_split";
+
+// Additional share mint metadata field holding the property's mpl-core asset
+pub const SHARE_METADATA_ASSET_FIELD: &str = "property_asset";
 
 pub const SEED_VOTE_RECORD_ACCOUNT: &[u8] = b"vote_record";
 pub const SEED_VOTE_STATE_ACCOUNT: &[u8] = b"vote_state";
 pub const SEED_VOTE_ROUND_ACCOUNT: &[u8] = b"vote_round_index";
+pub const SEED_VOTE_DELEGATION_ACCOUNT: &[u8] = b"vote_delegation";
+pub const SEED_VOTER_LOCK_ACCOUNT: &[u8] = b"voter_lock";
+pub const SEED_VOTER_LOCK_VAULT_ACCOUNT: &[u8] = b"voter_lock_vault";
+pub const SEED_VOTER_ESCROW_ACCOUNT: &[u8] = b"voter_escrow";
+pub const SEED_QUEUED_ACTION_ACCOUNT: &[u8] = b"queued_action";
+pub const SEED_VOTE_RESULT_ACCOUNT: &[u8] = b"vote_result";
+
+// Matches the max_len of VoteState.metadata_uri
+pub const MAX_PROPOSAL_URI_LENGTH: usize = 200;
 
 pub const SEED_AUCTION_VAULT_ACCOUNT: &[u8] = b"auction_vault";
 pub const SEED_AUCTION_STATE_ACCOUNT: &[u8] = b"auction_state";
+pub const SEED_AUCTION_CONTRIBUTION_ACCOUNT: &[u8] = b"auction_contribution";
+
+pub const SEED_TREASURY_ACCOUNT: &[u8] = b"treasury";
+pub const SEED_TREASURY_ALLOWANCE_ACCOUNT: &[u8] = b"treasury_allowance";
+
+pub const SEED_BUYOUT_STATE_ACCOUNT: &[u8] = b"buyout_state";
+
+pub const SEED_DISTRIBUTION_ACCOUNT: &[u8] = b"distribution";
+pub const SEED_DISTRIBUTION_CLAIM_ACCOUNT: &[u8] = b"distribution_claim";
+pub const SEED_MERKLE_DISTRIBUTION_ACCOUNT: &[u8] = b"merkle_distribution";
+pub const SEED_MERKLE_CLAIMS_ACCOUNT: &[u8] = b"merkle_claims";
+
+// Keeps the claim bitmap under the 10 KiB limit of accounts created by CPI
+pub const MAX_MERKLE_DISTRIBUTION_LEAVES: u32 = 80_000;
+
+// Time holders have to claim a distribution before the rest can be swept to the treasury (1 year)
+pub const DISTRIBUTION_CLAIM_WINDOW: i64 = 365 * 24 * 60 * 60;
+
+// Default delay between a proposal passing and its execution (2 days)
+pub const DEFAULT_TIMELOCK_DELAY: i64 = 2 * 24 * 60 * 60;
+// Time after voting ends during which a passed action can be queued before it expires (14 days)
+pub const PROPOSAL_QUEUE_WINDOW: i64 = 14 * 24 * 60 * 60;
+// Minimum delay before a passed config update executes, whatever the asset's timelock (2 days)
+pub const MIN_CONFIG_UPDATE_DELAY: i64 = 2 * 24 * 60 * 60;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+// Default share of total shares needed to create a vote round (1%)
+pub const DEFAULT_PROPOSAL_THRESHOLD_BPS: u16 = 100;
+// Default share of total shares that must vote for a round to pass (10%)
+pub const DEFAULT_QUORUM_BPS: u16 = 1_000;
+// Default share of total shares that must vote YES on a forced buyout (75%)
+pub const DEFAULT_SUPERMAJORITY_BPS: u16 = 7_500;
+// Lowest supermajority governance can set for a forced buyout, more than half of all shares
+pub const MIN_SUPERMAJORITY_BPS: u16 = 5_001;
+// Default share of total shares an auction must exceed to open tag-along rights (20%)
+pub const DEFAULT_TAG_ALONG_THRESHOLD_BPS: u16 = 2_000;
+// Default time other holders have to join a large auction (1 day)
+pub const DEFAULT_TAG_ALONG_WINDOW: i64 = 24 * 60 * 60;
+// Most tag-along sellers an auction takes, settlement pays all of them in one transaction
+pub const MAX_AUCTION_CONTRIBUTORS: u32 = 8;
+// Smallest share of all shares a tag-along seller can add to an auction (1%)
+pub const MIN_AUCTION_JOIN_BPS: u16 = 100;
+// Default period over which the asset manager's treasury allowance resets (30 days)
+pub const DEFAULT_ALLOWANCE_PERIOD: i64 = 30 * 24 * 60 * 60;
+// Shortest time between two waterfall runs, one per monthly period at most (28 days)
+pub const MIN_WATERFALL_INTERVAL: i64 = 28 * 24 * 60 * 60;
 
 // USDC mint address (devnet)
 pub const USDC_MINT_DEVNET: &str = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr";
@@ -49,4 +49,220 @@ pub enum ErrorCode {
 
     #[msg("Invalid highest bidder account.")]
     InvalidBidder,
+
+    #[msg("The voter has already voted in this round.")]
+    AlreadyVoted,
+
+    #[msg("A holder cannot delegate voting power to itself.")]
+    SelfDelegation,
+
+    #[msg("Invalid vote delegation account.")]
+    InvalidDelegation,
+
+    #[msg("The delegator has already voted in this round.")]
+    DelegatorAlreadyVoted,
+
+    #[msg("The voting end time must be in the future.")]
+    InvalidVotingEndTime,
+
+    #[msg("The voting period of this round has ended.")]
+    VotingClosed,
+
+    #[msg("The voting period of this round is still open.")]
+    VotingStillActive,
+
+    #[msg("The vote round has already been finalized.")]
+    VoteRoundFinalized,
+
+    #[msg("The proposal has not passed or was already executed.")]
+    ProposalNotPassed,
+
+    #[msg("The proposal does not carry this action.")]
+    InvalidProposalAction,
+
+    #[msg("Invalid payment recipient account.")]
+    InvalidRecipient,
+
+    #[msg("The shares are locked until the last round they voted in has ended.")]
+    SharesStillLocked,
+
+    #[msg("A treasury auction must sell shares and end after its vote round.")]
+    InvalidAuctionEndTime,
+
+    #[msg("The proposal is not queued for execution.")]
+    ProposalNotQueued,
+
+    #[msg("The proposal timelock has not expired yet.")]
+    TimelockNotExpired,
+
+    #[msg("The timelock delay cannot be negative.")]
+    InvalidTimelockDelay,
+
+    #[msg("Only the guardian or a passed counter-vote can cancel this proposal.")]
+    UnauthorizedCancellation,
+
+    #[msg("The queued action has neither executed nor been canceled.")]
+    ProposalStillQueued,
+
+    #[msg("The proposal metadata URI is too long.")]
+    ProposalUriTooLong,
+
+    #[msg("The creator does not hold enough shares to create a vote round.")]
+    ProposalThresholdNotMet,
+
+    #[msg("Basis points cannot exceed 10000.")]
+    InvalidBasisPoints,
+
+    #[msg("The proposal deposit must be zero or cover the rent-exempt minimum.")]
+    InvalidProposalDeposit,
+
+    #[msg("The vote round has not been finalized yet.")]
+    VoteRoundNotFinalized,
+
+    #[msg("The proposal action is still pending execution.")]
+    ProposalPending,
+
+    #[msg("The vote record does not belong to this vote round.")]
+    InvalidVoteRecord,
+
+    #[msg("The passed action was not queued in time and has expired.")]
+    ProposalExpired,
+
+    #[msg("The share mint must have supply and no mint or freeze authority.")]
+    InvalidShareMint,
+
+    #[msg("The share allocations do not match the recipient accounts or exceed the supply.")]
+    InvalidAllocation,
+
+    #[msg("Only the compliance officer or a passed proposal can freeze or thaw holders.")]
+    UnauthorizedComplianceAction,
+
+    #[msg("Only the asset manager or a passed proposal can update the property metadata.")]
+    UnauthorizedMetadataUpdate,
+
+    #[msg("The asset is no longer active.")]
+    AssetNotActive,
+
+    #[msg("Redeeming the property requires holding all of its shares.")]
+    IncompleteShares,
+
+    #[msg("Only the buyer named in the proposal can fund the buyout.")]
+    InvalidBuyer,
+
+    #[msg("The buyout was not approved by the required supermajority of all shares.")]
+    SupermajorityNotReached,
+
+    #[msg("The buyout supermajority must be more than half of all shares.")]
+    InvalidSupermajority,
+
+    #[msg("The property NFT is handed over once every share was sold into the buyout.")]
+    BuyoutSharesOutstanding,
+
+    #[msg("The tag-along window cannot be negative.")]
+    InvalidTagAlongWindow,
+
+    #[msg("The auction is not open for tag-along sellers.")]
+    JoinWindowClosed,
+
+    #[msg("The tag-along contributions do not match the auction.")]
+    InvalidContribution,
+
+    #[msg("The auction already has the maximum number of tag-along sellers.")]
+    AuctionFull,
+
+    #[msg("The tag-along contribution is below the minimum share of all shares.")]
+    JoinAmountTooSmall,
+
+    #[msg("Bidding opens once the tag-along window has closed.")]
+    BiddingNotOpen,
+
+    #[msg("The auction must end after its tag-along window so bidding can open.")]
+    AuctionTooShort,
+
+    #[msg("The auction has a winning bid and must be settled instead.")]
+    AuctionHasBids,
+
+    #[msg("Every tag-along contribution must be refunded before the creator reclaims the shares.")]
+    ContributionsOutstanding,
+
+    #[msg("The right of first refusal window must be positive, end before the auction and have an asking price.")]
+    InvalidRofrTerms,
+
+    #[msg("The right of first refusal window is not open.")]
+    RofrWindowClosed,
+
+    #[msg("The collection and portfolio accounts must be passed together and match.")]
+    InvalidPortfolio,
+
+    #[msg("Only the portfolio manager can add properties to the portfolio.")]
+    UnauthorizedPortfolioManager,
+
+    #[msg("The asset has been retired.")]
+    AssetRetired,
+
+    #[msg("Only the NFT owner after a redemption or a passed proposal can retire the asset.")]
+    UnauthorizedRetirement,
+
+    #[msg("Both sides of a share split ratio must be positive.")]
+    InvalidSplitRatio,
+
+    #[msg("A share split waits until every auction and vote round on the current shares closed.")]
+    ShareSplitBlocked,
+
+    #[msg("The share mint uses a transfer hook a share split can't carry over.")]
+    UnsupportedTransferHook,
+
+    #[msg("The compliance hook accounts are required for compliance-restricted shares.")]
+    MissingHookAccounts,
+
+    #[msg("Old shares are still waiting to be migrated to the new mint.")]
+    ShareSplitInProgress,
+
+    #[msg("Only the asset manager can fund distributions.")]
+    UnauthorizedDistributionDeposit,
+
+    #[msg("The distribution amount must be positive.")]
+    InvalidDistributionAmount,
+
+    #[msg("The holder is not owed anything from this distribution.")]
+    NothingToClaim,
+
+    #[msg("The claim window of the distribution has closed.")]
+    DistributionClaimsClosed,
+
+    #[msg("The distribution can only be swept once its claim window has closed.")]
+    DistributionClaimsOpen,
+
+    #[msg("The asset has no shares to pay income to.")]
+    NoSharesOutstanding,
+
+    #[msg("The snapshot lists more shares than the asset had when the income was deposited.")]
+    SnapshotExceedsSupply,
+
+    #[msg("The merkle proof does not match the distribution root.")]
+    InvalidMerkleProof,
+
+    #[msg("The distribution needs a positive amount and between one and 80,000 leaves.")]
+    InvalidMerkleDistribution,
+
+    #[msg("This leaf of the distribution was already claimed.")]
+    AlreadyClaimed,
+
+    #[msg("The allowance period must be positive.")]
+    InvalidAllowancePeriod,
+
+    #[msg("The payment exceeds what is left of the manager's allowance for this period.")]
+    AllowanceExceeded,
+
+    #[msg("Only the asset manager can spend the treasury allowance.")]
+    UnauthorizedTreasurySpend,
+
+    #[msg("The treasury deposit must be positive.")]
+    InvalidTreasuryDeposit,
+
+    #[msg("The debt service account must belong to the configured recipient.")]
+    InvalidDebtServiceAccount,
+
+    #[msg("The waterfall already ran for the current period.")]
+    WaterfallTooEarly,
 }
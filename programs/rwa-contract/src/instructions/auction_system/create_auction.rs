@@ -1,13 +1,25 @@
 use crate::constants::USDC_MINT_DEVNET;
-use crate::state::AssetState;
+use crate::instructions::{create_share_vault, transfer_shares};
+use crate::state::{AssetConfig, AssetState, AssetStatus};
 use crate::{error::ErrorCode, state::AuctionState};
-use crate::{SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_STATE_ACCOUNT};
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{
-    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+use crate::{
+    BPS_DENOMINATOR, SEED_ASSET_CONFIG_ACCOUNT, SEED_AUCTION_STATE_ACCOUNT,
+    SEED_AUCTION_VAULT_ACCOUNT, SEED_STATE_ACCOUNT,
 };
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+/// Right of first refusal offered to existing holders before the auction opens to the public
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RofrTerms {
+    /// Seconds after bidding opens during which only share holders can bid or buy
+    pub duration: i64,
+    /// USDC price at which a holder can buy the whole block during the window
+    pub asking_price: u64,
+}
 
 #[derive(Accounts)]
+#[instruction(auction_id: u64)]
 pub struct CreateAuction<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -24,13 +36,23 @@ pub struct CreateAuction<'info> {
     pub asset: AccountInfo<'info>,
 
     #[account(
+        mut,
         has_one = ft_mint,
         has_one = asset,
+        constraint = asset_state.status == AssetStatus::Active @ ErrorCode::AssetNotActive,
+        constraint = !asset_state.share_split_in_progress @ ErrorCode::ShareSplitInProgress,
         seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
         bump = asset_state.bump,
     )]
     pub asset_state: Account<'info, AssetState>,
 
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
     #[account(
         mut,
         token::mint = ft_mint.key(),
@@ -38,22 +60,19 @@ pub struct CreateAuction<'info> {
     )]
     pub token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: Share vault owning itself, created by the handler
     #[account(
-        init,
-        payer = payer,
-        token::mint = ft_mint,
-        token::authority = auction_vault,
-        token::token_program = token_program,
-        seeds = [SEED_AUCTION_VAULT_ACCOUNT, payer.key().as_ref()],
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, payer.key().as_ref(), auction_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+    pub auction_vault: UncheckedAccount<'info>,
 
     #[account(
         init,
         payer = payer,
         space = 8 + AuctionState::INIT_SPACE,
-        seeds = [SEED_AUCTION_STATE_ACCOUNT, payer.key().as_ref()],
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, payer.key().as_ref(), auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_state: Account<'info, AuctionState>,
@@ -62,10 +81,14 @@ pub struct CreateAuction<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn handle_create_auction(
-    ctx: Context<CreateAuction>,
+/// Compliance-restricted shares need the transfer hook accounts in `remaining_accounts`, and the
+/// auction vault registered through `register_program_account` beforehand
+pub fn handle_create_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateAuction<'info>>,
+    auction_id: u64,
     amount: u64,
     auction_end_time: i64,
+    rofr: Option<RofrTerms>,
 ) -> Result<()> {
     let token_account_data = &ctx.accounts.token_account;
 
@@ -73,27 +96,87 @@ pub fn handle_create_auction(
         return Err(ErrorCode::InsuficientTokenBalance.into());
     }
 
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.token_account.to_account_info(),
-        to: ctx.accounts.auction_vault.to_account_info(),
-        authority: ctx.accounts.payer.to_account_info(),
-        mint: ctx.accounts.ft_mint.to_account_info(),
-    };
+    let payer_key = ctx.accounts.payer.key();
+    let auction_id_bytes = auction_id.to_le_bytes();
+    let vault_seeds = &[
+        SEED_AUCTION_VAULT_ACCOUNT,
+        payer_key.as_ref(),
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction_vault],
+    ];
+    create_share_vault(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[&vault_seeds[..]],
+    )?;
 
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    transfer_checked(cpi_ctx, amount, ctx.accounts.ft_mint.decimals)?;
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        &[],
+    )?;
 
     let auction_state = &mut ctx.accounts.auction_state;
     auction_state.asset = ctx.accounts.asset.key();
     auction_state.auction_creator = ctx.accounts.payer.key();
+    auction_state.auction_id = auction_id;
     auction_state.ft_mint = ctx.accounts.ft_mint.key();
     auction_state.bid_token_mint = ctx.accounts.usdc_mint.key();
     auction_state.is_active = true;
     auction_state.highest_bid = 0;
     auction_state.highest_bidder = Pubkey::default();
     auction_state.auction_end_time = auction_end_time;
+    auction_state.total_amount = amount;
+    auction_state.contributor_count = 0;
+
+    // Blocks above the tag-along threshold open a window for other holders to join the sale.
+    // Bidding only opens after it, so bidders know the full block they are bidding on.
+    let now = Clock::get()?.unix_timestamp;
+    let asset_config = &ctx.accounts.asset_config;
+    let total_shares = ctx.accounts.asset_state.total_shares;
+    auction_state.join_end_time = if amount as u128 * BPS_DENOMINATOR as u128
+        > asset_config.tag_along_threshold_bps as u128 * total_shares as u128
+    {
+        let join_end_time = now.saturating_add(asset_config.tag_along_window);
+        require!(join_end_time < auction_end_time, ErrorCode::AuctionTooShort);
+        join_end_time
+    } else {
+        0
+    };
+
+    // The right of first refusal window starts when bidding opens
+    (auction_state.rofr_end_time, auction_state.asking_price) = match rofr {
+        Some(terms) => {
+            let rofr_end_time = now
+                .max(auction_state.join_end_time)
+                .checked_add(terms.duration)
+                .ok_or(ErrorCode::Overflow)?;
+            require!(
+                terms.duration > 0 && terms.asking_price > 0 && rofr_end_time < auction_end_time,
+                ErrorCode::InvalidRofrTerms
+            );
+            (rofr_end_time, terms.asking_price)
+        }
+        None => (0, 0),
+    };
+
     auction_state.bump = ctx.bumps.auction_state;
 
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_auctions = asset_state
+        .open_auctions
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
     Ok(())
 }
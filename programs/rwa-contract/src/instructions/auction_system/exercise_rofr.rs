@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::instructions::{pay_tag_along_sellers, split_tag_along_accounts, transfer_shares};
+use crate::state::{AssetState, AuctionState};
+use crate::{SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ExerciseRofr<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Auction creator is validated through auction_state has_one constraint
+    pub auction_creator: AccountInfo<'info>,
+
+    /// Mint of the tokenized asset being auctioned
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// USDC mint for bids
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = auction_creator,
+        has_one = ft_mint,
+        constraint = auction_state.bid_token_mint == usdc_mint.key() @ ErrorCode::InvalidBidToken,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump = auction_state.bump
+    )]
+    pub auction_state: Box<Account<'info, AuctionState>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STATE_ACCOUNT, auction_state.asset.as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = auction_state,
+        associated_token::token_program = token_program,
+    )]
+    pub bids_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Buyer's share account, proves the buyer is an existing holder and receives the block
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_share_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = auction_creator,
+        associated_token::token_program = token_program,
+    )]
+    pub auction_creator_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Current highest bidder's USDC account, refunded when a bid was already placed
+    #[account(mut, token::mint = usdc_mint)]
+    pub highest_bidder_usdc_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub system_program: Program<'info, System>,
+    /// Token program of the USDC bids
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program of the share mint, Token-2022 for compliance-restricted shares
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Lets an existing holder buy the whole auctioned block at the asking price during the right
+/// of first refusal window. Tag-along sellers and transfer hook accounts are passed as remaining
+/// accounts like in `settle_auction`.
+pub fn handle_exercise_rofr<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExerciseRofr<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction_state = &ctx.accounts.auction_state;
+
+    require!(auction_state.is_active, ErrorCode::AuctionAlreadySettled);
+    require!(
+        clock.unix_timestamp >= auction_state.join_end_time,
+        ErrorCode::BiddingNotOpen
+    );
+    require!(
+        clock.unix_timestamp < auction_state.rofr_end_time,
+        ErrorCode::RofrWindowClosed
+    );
+    require!(
+        ctx.accounts.buyer_share_account.amount > 0,
+        ErrorCode::NotTokenBalance
+    );
+
+    let usdc_decimals = ctx.accounts.usdc_mint.decimals;
+    let asking_price = auction_state.asking_price;
+    let highest_bid = auction_state.highest_bid;
+    let highest_bidder = auction_state.highest_bidder;
+    let auction_creator_key = ctx.accounts.auction_creator.key();
+    let auction_id_bytes = auction_state.auction_id.to_le_bytes();
+
+    // The buyer pays the asking price into the bids vault, which then pays out all sellers
+    let pay_accounts = TransferChecked {
+        from: ctx.accounts.buyer_usdc_account.to_account_info(),
+        to: ctx.accounts.bids_vault.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let pay_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), pay_accounts);
+    transfer_checked(pay_ctx, asking_price, usdc_decimals)?;
+
+    let auction_state_seeds = &[
+        SEED_AUCTION_STATE_ACCOUNT,
+        auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
+        &[auction_state.bump],
+    ];
+    let signer_seeds = &[&auction_state_seeds[..]];
+
+    // Refund the bid the buyer pre-empted
+    if highest_bid > 0 {
+        let refund_account = ctx
+            .accounts
+            .highest_bidder_usdc_account
+            .as_ref()
+            .ok_or(ErrorCode::InvalidBidder)?;
+        require_keys_eq!(
+            refund_account.owner,
+            highest_bidder,
+            ErrorCode::InvalidBidder
+        );
+
+        let refund_accounts = TransferChecked {
+            from: ctx.accounts.bids_vault.to_account_info(),
+            to: refund_account.to_account_info(),
+            authority: ctx.accounts.auction_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let refund_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            refund_accounts,
+            signer_seeds,
+        );
+        transfer_checked(refund_ctx, highest_bid, usdc_decimals)?;
+    }
+
+    let (sellers, hook_accounts) =
+        split_tag_along_accounts(&ctx.accounts.auction_state, ctx.remaining_accounts)?;
+    let contributors_paid = pay_tag_along_sellers(
+        &ctx.accounts.auction_state,
+        asking_price,
+        sellers,
+        &ctx.accounts.bids_vault,
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.token_program,
+        signer_seeds,
+    )?;
+    let creator_proceeds = asking_price
+        .checked_sub(contributors_paid)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let creator_accounts = TransferChecked {
+        from: ctx.accounts.bids_vault.to_account_info(),
+        to: ctx.accounts.auction_creator_usdc_account.to_account_info(),
+        authority: ctx.accounts.auction_state.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let creator_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        creator_accounts,
+        signer_seeds,
+    );
+    transfer_checked(creator_ctx, creator_proceeds, usdc_decimals)?;
+
+    // Move the whole block to the buyer
+    let vault_seeds = &[
+        SEED_AUCTION_VAULT_ACCOUNT,
+        auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction_vault],
+    ];
+    let vault_signer_seeds = &[&vault_seeds[..]];
+
+    let shares = ctx.accounts.auction_vault.amount;
+    transfer_shares(
+        &ctx.accounts.share_token_program.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.buyer_share_account.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        hook_accounts,
+        shares,
+        ctx.accounts.ft_mint.decimals,
+        vault_signer_seeds,
+    )?;
+
+    let auction_state = &mut ctx.accounts.auction_state;
+    auction_state.highest_bid = asking_price;
+    auction_state.highest_bidder = ctx.accounts.buyer.key();
+    auction_state.is_active = false;
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_auctions = asset_state.open_auctions.saturating_sub(1);
+
+    msg!(
+        "Holder {} bought {} shares at the asking price of {}",
+        ctx.accounts.buyer.key(),
+        shares,
+        asking_price
+    );
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::transfer_shares;
+use crate::state::{AuctionContribution, AuctionState};
+use crate::{
+    BPS_DENOMINATOR, MAX_AUCTION_CONTRIBUTORS, MIN_AUCTION_JOIN_BPS,
+    SEED_AUCTION_CONTRIBUTION_ACCOUNT, SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct JoinAuction<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Auction creator is validated through auction_state PDA
+    pub auction_creator: AccountInfo<'info>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = auction_creator,
+        has_one = ft_mint,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump = auction_state.bump
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = seller,
+    )]
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + AuctionContribution::INIT_SPACE,
+        seeds = [SEED_AUCTION_CONTRIBUTION_ACCOUNT, auction_state.key().as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, AuctionContribution>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Adds the seller's shares to a large auction during its tag-along window. Each seller must add
+/// at least `MIN_AUCTION_JOIN_BPS` of all shares and an auction takes at most
+/// `MAX_AUCTION_CONTRIBUTORS` of them. Transfer hook accounts of compliance-restricted shares go
+/// in `remaining_accounts`.
+pub fn handle_join_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, JoinAuction<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction_state = &mut ctx.accounts.auction_state;
+
+    require!(auction_state.is_active, ErrorCode::AuctionAlreadySettled);
+    require!(
+        clock.unix_timestamp < auction_state.join_end_time,
+        ErrorCode::JoinWindowClosed
+    );
+    require!(amount > 0, ErrorCode::NoShares);
+    // Settlement pays every tag-along seller in one transaction, so their number is capped and
+    // dust contributions can't take up the slots
+    require!(
+        auction_state.contributor_count < MAX_AUCTION_CONTRIBUTORS,
+        ErrorCode::AuctionFull
+    );
+    require!(
+        amount as u128 * BPS_DENOMINATOR as u128
+            >= MIN_AUCTION_JOIN_BPS as u128 * ctx.accounts.ft_mint.supply as u128,
+        ErrorCode::JoinAmountTooSmall
+    );
+    require!(
+        ctx.accounts.seller_token_account.amount >= amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.seller.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        &[],
+    )?;
+
+    auction_state.total_amount = auction_state
+        .total_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    auction_state.contributor_count = auction_state
+        .contributor_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let contribution = &mut ctx.accounts.contribution;
+    contribution.auction_state = auction_state.key();
+    contribution.seller = ctx.accounts.seller.key();
+    contribution.amount = amount;
+    contribution.paid = false;
+    contribution.bump = ctx.bumps.contribution;
+
+    msg!(
+        "Seller {} joined the auction with {} shares",
+        contribution.seller,
+        amount
+    );
+
+    Ok(())
+}
@@ -6,3 +6,15 @@ pub use place_bid::*;
 
 pub mod settle_auction;
 pub use settle_auction::*;
+
+pub mod join_auction;
+pub use join_auction::*;
+
+pub mod refund_contribution;
+pub use refund_contribution::*;
+
+pub mod reclaim_unsold_shares;
+pub use reclaim_unsold_shares::*;
+
+pub mod exercise_rofr;
+pub use exercise_rofr::*;
@@ -31,7 +31,7 @@ pub struct PlaceBid<'info> {
 
     /// CHECK: This PDA serves as authority for the bids vault
     #[account(
-        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref()],
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_state_pda: UncheckedAccount<'info>,
@@ -41,7 +41,7 @@ pub struct PlaceBid<'info> {
         has_one = auction_creator,
         has_one = asset,
         constraint = auction_state.bid_token_mint == usdc_mint.key() @ ErrorCode::InvalidBidToken,
-        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref()],
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump = auction_state.bump
         )]
     pub auction_state: Account<'info, AuctionState>,
@@ -62,6 +62,13 @@ pub struct PlaceBid<'info> {
     )]
     pub bids_vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Bidder's share account, required while the right of first refusal window is open
+    #[account(
+        token::mint = asset_state.ft_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_share_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
@@ -74,6 +81,17 @@ pub fn handle_place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
     let auction_state = &mut ctx.accounts.auction_state;
     require!(clock.unix_timestamp < auction_state.auction_end_time, ErrorCode::AuctionEnded);
+    require!(clock.unix_timestamp >= auction_state.join_end_time, ErrorCode::BiddingNotOpen);
+
+    // Only existing holders can bid during the right of first refusal window
+    if clock.unix_timestamp < auction_state.rofr_end_time {
+        let holds_shares = ctx
+            .accounts
+            .bidder_share_account
+            .as_ref()
+            .is_some_and(|account| account.amount > 0);
+        require!(holds_shares, ErrorCode::NotTokenBalance);
+    }
 
     // Check if bidder has enough USDC balance
     let bidder_usdc_account = &ctx.accounts.bidder_usdc_account;
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::transfer_shares;
+use crate::state::{AssetState, AuctionState};
+use crate::{SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ReclaimUnsoldShares<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: Auction creator is validated through auction_state has_one constraint
+    pub auction_creator: AccountInfo<'info>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = auction_creator,
+        has_one = ft_mint,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump = auction_state.bump
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STATE_ACCOUNT, auction_state.asset.as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = auction_creator,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns the creator's shares and closes an auction that ended without bids, once every
+/// tag-along seller was refunded through `refund_contribution`. Anyone can call it, which also
+/// lets treasury auctions return their shares to the treasury. Transfer hook accounts of
+/// compliance-restricted shares go in `remaining_accounts`.
+pub fn handle_reclaim_unsold_shares<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReclaimUnsoldShares<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction_state = &ctx.accounts.auction_state;
+
+    require!(auction_state.is_active, ErrorCode::AuctionAlreadySettled);
+    require!(
+        clock.unix_timestamp >= auction_state.auction_end_time,
+        ErrorCode::AuctionStillActive
+    );
+    require!(auction_state.highest_bid == 0, ErrorCode::AuctionHasBids);
+    require!(
+        auction_state.contributor_count == 0,
+        ErrorCode::ContributionsOutstanding
+    );
+
+    let amount = ctx.accounts.auction_vault.amount;
+    if amount > 0 {
+        let auction_creator_key = ctx.accounts.auction_creator.key();
+        let auction_id_bytes = auction_state.auction_id.to_le_bytes();
+        let vault_seeds = &[
+            SEED_AUCTION_VAULT_ACCOUNT,
+            auction_creator_key.as_ref(),
+            auction_id_bytes.as_ref(),
+            &[ctx.bumps.auction_vault],
+        ];
+
+        transfer_shares(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.auction_vault.to_account_info(),
+            &ctx.accounts.ft_mint.to_account_info(),
+            &ctx.accounts.creator_token_account.to_account_info(),
+            &ctx.accounts.auction_vault.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.ft_mint.decimals,
+            &[&vault_seeds[..]],
+        )?;
+    }
+
+    ctx.accounts.auction_state.is_active = false;
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_auctions = asset_state.open_auctions.saturating_sub(1);
+
+    msg!(
+        "Auction closed unsold, {} shares returned to {}",
+        amount,
+        ctx.accounts.auction_creator.key()
+    );
+
+    Ok(())
+}
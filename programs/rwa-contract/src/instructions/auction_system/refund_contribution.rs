@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::transfer_shares;
+use crate::state::{AuctionContribution, AuctionState};
+use crate::{
+    SEED_AUCTION_CONTRIBUTION_ACCOUNT, SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct RefundContribution<'info> {
+    pub payer: Signer<'info>,
+
+    /// CHECK: Tag-along seller is validated through contribution has_one constraint
+    #[account(mut)]
+    pub seller: AccountInfo<'info>,
+
+    /// CHECK: Auction creator is validated through auction_state has_one constraint
+    pub auction_creator: AccountInfo<'info>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        has_one = auction_creator,
+        has_one = ft_mint,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump = auction_state.bump
+    )]
+    pub auction_state: Account<'info, AuctionState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = seller,
+        has_one = auction_state,
+        has_one = seller,
+        seeds = [SEED_AUCTION_CONTRIBUTION_ACCOUNT, auction_state.key().as_ref(), seller.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, AuctionContribution>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = seller,
+    )]
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns a tag-along seller's shares once an auction ended without bids. Anyone can call it,
+/// so an absent seller can't keep the creator from reclaiming the rest of the block. Transfer
+/// hook accounts of compliance-restricted shares go in `remaining_accounts`.
+pub fn handle_refund_contribution<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefundContribution<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let auction_state = &ctx.accounts.auction_state;
+
+    require!(auction_state.is_active, ErrorCode::AuctionAlreadySettled);
+    require!(
+        clock.unix_timestamp >= auction_state.auction_end_time,
+        ErrorCode::AuctionStillActive
+    );
+    require!(auction_state.highest_bid == 0, ErrorCode::AuctionHasBids);
+
+    let amount = ctx.accounts.contribution.amount;
+    let auction_creator_key = ctx.accounts.auction_creator.key();
+    let auction_id_bytes = auction_state.auction_id.to_le_bytes();
+    let vault_seeds = &[
+        SEED_AUCTION_VAULT_ACCOUNT,
+        auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction_vault],
+    ];
+
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.seller_token_account.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        &[&vault_seeds[..]],
+    )?;
+
+    let auction_state = &mut ctx.accounts.auction_state;
+    auction_state.total_amount = auction_state
+        .total_amount
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    auction_state.contributor_count = auction_state
+        .contributor_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    msg!(
+        "Refunded {} unsold shares to seller {}",
+        amount,
+        ctx.accounts.seller.key()
+    );
+
+    Ok(())
+}
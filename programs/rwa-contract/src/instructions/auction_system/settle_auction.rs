@@ -5,8 +5,12 @@ use anchor_spl::token_interface::{
 };
 
 use crate::error::ErrorCode;
-use crate::state::{AssetState, AuctionState};
-use crate::{SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_STATE_ACCOUNT};
+use crate::instructions::transfer_shares;
+use crate::state::{AssetState, AuctionContribution, AuctionState};
+use crate::{
+    SEED_AUCTION_CONTRIBUTION_ACCOUNT, SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT,
+    SEED_STATE_ACCOUNT,
+};
 
 #[derive(Accounts)]
 pub struct SettleAuction<'info> {
@@ -32,12 +36,14 @@ pub struct SettleAuction<'info> {
 
     #[account(
         mut,
-        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref()],
+        has_one = asset,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_state: Box<Account<'info, AuctionState>>,
 
     #[account(
+        mut,
         seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
         bump
     )]
@@ -45,7 +51,7 @@ pub struct SettleAuction<'info> {
 
     /// CHECK: PDA authority for auction vault
     #[account(
-        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref()],
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_vault_pda: UncheckedAccount<'info>,
@@ -53,14 +59,14 @@ pub struct SettleAuction<'info> {
     // Vault holding the asset tokens being auctioned (self-custodied)
     #[account(
         mut,
-        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref()],
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: PDA authority for bids vault
     #[account(
-        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref()],
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
         bump
     )]
     pub auction_state_pda: UncheckedAccount<'info>,
@@ -85,16 +91,24 @@ pub struct SettleAuction<'info> {
         payer = settler,
         associated_token::mint = ft_mint,
         associated_token::authority = highest_bidder,
-        associated_token::token_program = token_program,
+        associated_token::token_program = share_token_program,
     )]
     pub highest_bidder_asset_account: InterfaceAccount<'info, TokenAccount>,
 
     pub system_program: Program<'info, System>,
+    /// Token program of the USDC bids
     pub token_program: Interface<'info, TokenInterface>,
+    /// Token program of the share mint, Token-2022 for compliance-restricted shares
+    pub share_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+/// Tag-along sellers are passed as remaining accounts, one
+/// [contribution, seller USDC account] pair per seller that joined the auction, followed by the
+/// transfer hook accounts of compliance-restricted shares
+pub fn handle_settle_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+) -> Result<()> {
     let clock = Clock::get()?;
     let auction_state = &mut ctx.accounts.auction_state;
 
@@ -162,16 +176,37 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
     let auction_creator_key = ctx.accounts.auction_creator.key();
     let highest_bid_amount = auction_state.highest_bid;
     let auction_bump = auction_state.bump;
+    let auction_id_bytes = auction_state.auction_id.to_le_bytes();
 
     // Generate signer seeds for the auction_state PDA
     let auction_state_seeds = &[
         SEED_AUCTION_STATE_ACCOUNT,
         auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
         &[auction_bump],
     ];
     let signer_seeds = &[&auction_state_seeds[..]];
 
-    // Transfer the winning USDC bid from bids_vault to auction creator
+    let (sellers, hook_accounts) =
+        split_tag_along_accounts(&ctx.accounts.auction_state, ctx.remaining_accounts)?;
+
+    // Pay each tag-along seller its pro-rata share of the winning bid
+    let contributors_paid = pay_tag_along_sellers(
+        &ctx.accounts.auction_state,
+        highest_bid_amount,
+        sellers,
+        &ctx.accounts.bids_vault,
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.token_program,
+        signer_seeds,
+    )?;
+
+    // The auction creator receives its own share plus the rounding remainder
+    let creator_proceeds = highest_bid_amount
+        .checked_sub(contributors_paid)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Transfer the creator's part of the winning USDC bid from bids_vault to auction creator
     let transfer_bid_accounts = TransferChecked {
         from: ctx.accounts.bids_vault.to_account_info(),
         to: ctx.accounts.auction_creator_usdc_account.to_account_info(),
@@ -185,40 +220,44 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
         signer_seeds,
     );
 
-    transfer_checked(transfer_bid_ctx, highest_bid_amount, usdc_decimals)?;
+    transfer_checked(transfer_bid_ctx, creator_proceeds, usdc_decimals)?;
 
     // Generate signer seeds for the auction_vault PDA
     let vault_seeds = &[
         SEED_AUCTION_VAULT_ACCOUNT,
         auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
         &[ctx.bumps.auction_vault_pda],
     ];
     let vault_signer_seeds = &[&vault_seeds[..]];
 
-    // Transfer the auctioned asset tokens from auction_vault to highest bidder
-    let transfer_tokens_accounts = TransferChecked {
-        from: ctx.accounts.auction_vault.to_account_info(),
-        to: ctx.accounts.highest_bidder_asset_account.to_account_info(),
-        authority: ctx.accounts.auction_vault_pda.to_account_info(),
-        mint: ctx.accounts.ft_mint.to_account_info(),
-    };
-
-    let transfer_tokens_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_tokens_accounts,
-        vault_signer_seeds,
-    );
-
+    // Transfer the auctioned asset tokens from auction_vault to highest bidder. The vault is
+    // already empty when a buyout claimed the auctioned shares.
     let auction_vault_amount = ctx.accounts.auction_vault.amount;
-    transfer_checked(transfer_tokens_ctx, auction_vault_amount, asset_decimals)?;
+    if auction_vault_amount > 0 {
+        transfer_shares(
+            &ctx.accounts.share_token_program.to_account_info(),
+            &ctx.accounts.auction_vault.to_account_info(),
+            &ctx.accounts.ft_mint.to_account_info(),
+            &ctx.accounts.highest_bidder_asset_account.to_account_info(),
+            &ctx.accounts.auction_vault_pda.to_account_info(),
+            hook_accounts,
+            auction_vault_amount,
+            asset_decimals,
+            vault_signer_seeds,
+        )?;
+    }
 
     // Mark auction as settled
     ctx.accounts.auction_state.is_active = false;
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_auctions = asset_state.open_auctions.saturating_sub(1);
 
     msg!("Auction settled successfully!");
     msg!(
-        "Winning bid: {} transferred to auction creator",
-        highest_bid_amount
+        "Winning bid: {} transferred to auction creator, {} to tag-along sellers",
+        creator_proceeds,
+        contributors_paid
     );
     msg!(
         "Tokens: {} transferred to highest bidder",
@@ -227,3 +266,95 @@ pub fn handle_settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
 
     Ok(())
 }
+
+/// Splits `remaining_accounts` into the [contribution, seller USDC account] pairs of the tag-along
+/// sellers and the transfer hook accounts that follow them
+pub fn split_tag_along_accounts<'info>(
+    auction_state: &Account<'info, AuctionState>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<(&'info [AccountInfo<'info>], &'info [AccountInfo<'info>])> {
+    let seller_accounts = 2 * auction_state.contributor_count as usize;
+    require!(
+        remaining_accounts.len() >= seller_accounts,
+        ErrorCode::InvalidContribution
+    );
+    Ok(remaining_accounts.split_at(seller_accounts))
+}
+
+/// Splits `sale_price` pro rata across the tag-along sellers of an auction and returns what they
+/// were paid. `sellers` holds one [contribution, seller USDC account] pair per contribution.
+pub fn pay_tag_along_sellers<'info>(
+    auction_state: &Account<'info, AuctionState>,
+    sale_price: u64,
+    sellers: &'info [AccountInfo<'info>],
+    bids_vault: &InterfaceAccount<'info, TokenAccount>,
+    usdc_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    // Every tag-along seller must be paid in the same transaction, join_auction caps their number
+    require!(
+        sellers.len() == 2 * auction_state.contributor_count as usize,
+        ErrorCode::InvalidContribution
+    );
+
+    let auction_state_key = auction_state.key();
+    let mut contributors_paid: u64 = 0;
+    for pair in sellers.chunks(2) {
+        let mut contribution = Account::<AuctionContribution>::try_from(&pair[0])?;
+        let (expected_contribution, _) = Pubkey::find_program_address(
+            &[
+                SEED_AUCTION_CONTRIBUTION_ACCOUNT,
+                auction_state_key.as_ref(),
+                contribution.seller.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            contribution.key(),
+            expected_contribution,
+            ErrorCode::InvalidContribution
+        );
+        require!(!contribution.paid, ErrorCode::InvalidContribution);
+
+        let seller_usdc_account = InterfaceAccount::<TokenAccount>::try_from(&pair[1])?;
+        require_keys_eq!(
+            seller_usdc_account.owner,
+            contribution.seller,
+            ErrorCode::InvalidContribution
+        );
+        require_keys_eq!(
+            seller_usdc_account.mint,
+            usdc_mint.key(),
+            ErrorCode::InvalidBidToken
+        );
+
+        let proceeds = u64::try_from(
+            sale_price as u128 * contribution.amount as u128 / auction_state.total_amount as u128,
+        )
+        .map_err(|_| ErrorCode::Overflow)?;
+
+        let transfer_proceeds_accounts = TransferChecked {
+            from: bids_vault.to_account_info(),
+            to: pair[1].clone(),
+            authority: auction_state.to_account_info(),
+            mint: usdc_mint.to_account_info(),
+        };
+        let transfer_proceeds_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            transfer_proceeds_accounts,
+            signer_seeds,
+        );
+        transfer_checked(transfer_proceeds_ctx, proceeds, usdc_mint.decimals)?;
+
+        // Marking the contribution paid also rejects the same pair being passed twice
+        contribution.paid = true;
+        contribution.exit(&crate::ID)?;
+
+        contributors_paid = contributors_paid
+            .checked_add(proceeds)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+
+    Ok(contributors_paid)
+}
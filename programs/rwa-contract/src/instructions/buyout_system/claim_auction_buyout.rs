@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    burn, transfer_checked, Burn, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::instructions::pay_buyout_claim;
+use crate::state::{AssetState, AssetStatus, AuctionState, BuyoutState};
+use crate::{
+    SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_BUYOUT_STATE_ACCOUNT,
+    SEED_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ClaimAuctionBuyout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(mut)]
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Auction creator is validated through auction_state has_one constraint
+    pub auction_creator: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = ft_mint,
+        has_one = auction_creator,
+        constraint = auction_state.bid_token_mint == usdc_mint.key() @ ErrorCode::InvalidBidToken,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump = auction_state.bump
+    )]
+    pub auction_state: Box<Account<'info, AuctionState>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, auction_creator.key().as_ref(), auction_state.auction_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = auction_state,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub bids_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Current highest bidder's USDC account, refunded when a bid was already placed
+    #[account(mut, token::mint = usdc_mint)]
+    pub highest_bidder_usdc_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = usdc_mint,
+        seeds = [SEED_BUYOUT_STATE_ACCOUNT, asset.key().as_ref(), buyout_state.vote_state.as_ref()],
+        bump = buyout_state.bump,
+    )]
+    pub buyout_state: Box<Account<'info, BuyoutState>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = buyout_state,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub buyout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Claims the buyout for the shares of an open auction. The shares are burned, a pending bid is
+/// refunded and the buyout price becomes the winning bid of the buyer, so the auction ends now
+/// and `settle_auction` pays the creator and tag-along sellers as for any other sale.
+pub fn handle_claim_auction_buyout(ctx: Context<ClaimAuctionBuyout>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::BuyoutPending,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        ctx.accounts.auction_state.is_active,
+        ErrorCode::AuctionAlreadySettled
+    );
+
+    let shares = ctx.accounts.auction_vault.amount;
+    require!(shares > 0, ErrorCode::NoShares);
+
+    let auction_creator_key = ctx.accounts.auction_creator.key();
+    let auction_id_bytes = ctx.accounts.auction_state.auction_id.to_le_bytes();
+    let vault_seeds = &[
+        SEED_AUCTION_VAULT_ACCOUNT,
+        auction_creator_key.as_ref(),
+        auction_id_bytes.as_ref(),
+        &[ctx.bumps.auction_vault],
+    ];
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ft_mint.to_account_info(),
+                from: ctx.accounts.auction_vault.to_account_info(),
+                authority: ctx.accounts.auction_vault.to_account_info(),
+            },
+            &[&vault_seeds[..]],
+        ),
+        shares,
+    )?;
+
+    // Refund the bid the buyout pre-empted
+    let highest_bid = ctx.accounts.auction_state.highest_bid;
+    if highest_bid > 0 {
+        let refund_account = ctx
+            .accounts
+            .highest_bidder_usdc_account
+            .as_ref()
+            .ok_or(ErrorCode::InvalidBidder)?;
+        require_keys_eq!(
+            refund_account.owner,
+            ctx.accounts.auction_state.highest_bidder,
+            ErrorCode::InvalidBidder
+        );
+
+        let auction_state_seeds = &[
+            SEED_AUCTION_STATE_ACCOUNT,
+            auction_creator_key.as_ref(),
+            auction_id_bytes.as_ref(),
+            &[ctx.accounts.auction_state.bump],
+        ];
+        let signer_seeds = &[&auction_state_seeds[..]];
+        let refund_accounts = TransferChecked {
+            from: ctx.accounts.bids_vault.to_account_info(),
+            to: refund_account.to_account_info(),
+            authority: ctx.accounts.auction_state.to_account_info(),
+            mint: ctx.accounts.usdc_mint.to_account_info(),
+        };
+        let refund_ctx = CpiContext::new_with_signer(
+            ctx.accounts.usdc_token_program.to_account_info(),
+            refund_accounts,
+            signer_seeds,
+        );
+        transfer_checked(refund_ctx, highest_bid, ctx.accounts.usdc_mint.decimals)?;
+    }
+
+    let payment = pay_buyout_claim(
+        &mut ctx.accounts.buyout_state,
+        &ctx.accounts.buyout_vault,
+        &ctx.accounts.bids_vault.to_account_info(),
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.usdc_token_program,
+        shares,
+        ctx.accounts.ft_mint.decimals,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let auction_state = &mut ctx.accounts.auction_state;
+    auction_state.highest_bid = payment;
+    auction_state.highest_bidder = ctx.accounts.buyout_state.buyer;
+    auction_state.auction_end_time = auction_state.auction_end_time.min(now);
+    auction_state.join_end_time = auction_state.join_end_time.min(now);
+    auction_state.rofr_end_time = 0;
+
+    msg!(
+        "Auction {} sold {} shares to the buyout for {} USDC",
+        auction_state.key(),
+        shares,
+        payment
+    );
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    burn, thaw_account, transfer_checked, Burn, Mint, ThawAccount, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, AssetStatus, BuyoutState};
+use crate::{SEED_BUYOUT_STATE_ACCOUNT, SEED_FREEZE_AUTHORITY_ACCOUNT, SEED_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ClaimBuyout<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(mut)]
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PDA set as the freeze authority of the share mint, thaws frozen holders
+    #[account(
+        seeds = [SEED_FREEZE_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = usdc_mint,
+        seeds = [SEED_BUYOUT_STATE_ACCOUNT, asset.key().as_ref(), buyout_state.vote_state.as_ref()],
+        bump = buyout_state.bump,
+    )]
+    pub buyout_state: Box<Account<'info, BuyoutState>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = buyout_state,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub buyout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub holder_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Burns the holder's shares for their pro-rata part of the buyout. Frozen holders are thawed
+/// first, the forced sale takes their shares like everyone else's.
+pub fn handle_claim_buyout(ctx: Context<ClaimBuyout>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::BuyoutPending,
+        ErrorCode::AssetNotActive
+    );
+
+    let shares = ctx.accounts.holder_token_account.amount;
+    require!(shares > 0, ErrorCode::NoShares);
+
+    if ctx.accounts.holder_token_account.is_frozen() {
+        let asset_key = ctx.accounts.asset.key();
+        let freeze_seeds = &[
+            SEED_FREEZE_AUTHORITY_ACCOUNT,
+            asset_key.as_ref(),
+            &[ctx.bumps.freeze_authority],
+        ];
+
+        thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.holder_token_account.to_account_info(),
+                mint: ctx.accounts.ft_mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            },
+            &[&freeze_seeds[..]],
+        ))?;
+    }
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ft_mint.to_account_info(),
+                from: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        shares,
+    )?;
+
+    let payment = pay_buyout_claim(
+        &mut ctx.accounts.buyout_state,
+        &ctx.accounts.buyout_vault,
+        &ctx.accounts.holder_usdc_account.to_account_info(),
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.usdc_token_program,
+        shares,
+        ctx.accounts.ft_mint.decimals,
+    )?;
+
+    msg!(
+        "Holder {} received {} USDC for {} shares",
+        ctx.accounts.holder.key(),
+        payment,
+        shares
+    );
+
+    Ok(())
+}
+
+/// Books `shares` base units as claimed and pays their price out of the buyout vault to
+/// `recipient`. The last claim also collects the rounding dust, after it `complete_buyout` can
+/// hand the property NFT to the buyer.
+#[allow(clippy::too_many_arguments)]
+pub fn pay_buyout_claim<'info>(
+    buyout_state: &mut Account<'info, BuyoutState>,
+    buyout_vault: &InterfaceAccount<'info, TokenAccount>,
+    recipient: &AccountInfo<'info>,
+    usdc_mint: &InterfaceAccount<'info, Mint>,
+    usdc_token_program: &Interface<'info, TokenInterface>,
+    shares: u64,
+    share_decimals: u8,
+) -> Result<u64> {
+    buyout_state.remaining_shares = buyout_state
+        .remaining_shares
+        .checked_sub(shares)
+        .ok_or(ErrorCode::Overflow)?;
+    let is_last_claim = buyout_state.remaining_shares == 0;
+
+    let payment = if is_last_claim {
+        buyout_vault.amount
+    } else {
+        BuyoutState::payment_for(buyout_state.price_per_share, shares, share_decimals)?
+    };
+
+    let buyout_seeds = &[
+        SEED_BUYOUT_STATE_ACCOUNT,
+        buyout_state.asset.as_ref(),
+        buyout_state.vote_state.as_ref(),
+        &[buyout_state.bump],
+    ];
+    let signer_seeds = &[&buyout_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: buyout_vault.to_account_info(),
+        to: recipient.clone(),
+        authority: buyout_state.to_account_info(),
+        mint: usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        usdc_token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, payment, usdc_mint.decimals)?;
+
+    if is_last_claim {
+        msg!(
+            "Every share of property {} sold into the buyout",
+            buyout_state.asset
+        );
+    }
+
+    Ok(payment)
+}
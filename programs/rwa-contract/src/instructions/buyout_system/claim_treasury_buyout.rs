@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::pay_buyout_claim;
+use crate::state::{AssetState, AssetStatus, BuyoutState};
+use crate::{SEED_BUYOUT_STATE_ACCOUNT, SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ClaimTreasuryBuyout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(mut)]
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = ft_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = usdc_mint,
+        seeds = [SEED_BUYOUT_STATE_ACCOUNT, asset.key().as_ref(), buyout_state.vote_state.as_ref()],
+        bump = buyout_state.bump,
+    )]
+    pub buyout_state: Box<Account<'info, BuyoutState>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = buyout_state,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub buyout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Claims the buyout for the shares held by the asset treasury, paying into its USDC account.
+/// Anyone can call it, so treasury shares never keep the buyout from completing.
+pub fn handle_claim_treasury_buyout(ctx: Context<ClaimTreasuryBuyout>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::BuyoutPending,
+        ErrorCode::AssetNotActive
+    );
+
+    let shares = ctx.accounts.treasury_token_account.amount;
+    require!(shares > 0, ErrorCode::NoShares);
+
+    let asset_key = ctx.accounts.asset.key();
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ft_mint.to_account_info(),
+                from: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[&treasury_seeds[..]],
+        ),
+        shares,
+    )?;
+
+    let payment = pay_buyout_claim(
+        &mut ctx.accounts.buyout_state,
+        &ctx.accounts.buyout_vault,
+        &ctx.accounts.treasury_usdc_account.to_account_info(),
+        &ctx.accounts.usdc_mint,
+        &ctx.accounts.usdc_token_program,
+        shares,
+        ctx.accounts.ft_mint.decimals,
+    )?;
+
+    msg!("Treasury received {} USDC for {} shares", payment, shares);
+
+    Ok(())
+}
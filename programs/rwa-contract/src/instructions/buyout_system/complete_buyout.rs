@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::TransferV1CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, AssetStatus, BuyoutState};
+use crate::{SEED_BUYOUT_STATE_ACCOUNT, SEED_STATE_ACCOUNT, SEED_VAULT_OWNER_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct CompleteBuyout<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint and by mpl-core
+    #[account(mut)]
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        has_one = asset,
+        has_one = buyer,
+        seeds = [SEED_BUYOUT_STATE_ACCOUNT, asset.key().as_ref(), buyout_state.vote_state.as_ref()],
+        bump = buyout_state.bump,
+    )]
+    pub buyout_state: Account<'info, BuyoutState>,
+
+    /// CHECK: Buyer of the property, validated through buyout_state has_one constraint
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: PDA holding the property NFT
+    #[account(
+        seeds = [SEED_VAULT_OWNER_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Portfolio collection of the property NFT, required by mpl-core for assets in one
+    #[account(
+        mut,
+        constraint = asset_state.collection == Some(collection.key()) @ ErrorCode::InvalidPortfolio
+    )]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Hands the property NFT to the buyer once every outstanding share was sold into the buyout,
+/// callable by anyone
+pub fn handle_complete_buyout(ctx: Context<CompleteBuyout>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::BuyoutPending,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        ctx.accounts.buyout_state.remaining_shares == 0,
+        ErrorCode::BuyoutSharesOutstanding
+    );
+
+    let asset_key = ctx.accounts.asset.key();
+    let owner_seeds = &[
+        SEED_VAULT_OWNER_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.owner],
+    ];
+
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(
+            ctx.accounts
+                .collection
+                .as_ref()
+                .map(|collection| collection.as_ref()),
+        )
+        .payer(&ctx.accounts.payer.to_account_info())
+        .authority(Some(&ctx.accounts.owner.to_account_info()))
+        .new_owner(&ctx.accounts.buyer.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(&[owner_seeds])?;
+
+    ctx.accounts.asset_state.status = AssetStatus::Redeemed;
+
+    msg!(
+        "Buyout of property {} completed, NFT transferred to {}",
+        asset_key,
+        ctx.accounts.buyer.key()
+    );
+
+    Ok(())
+}
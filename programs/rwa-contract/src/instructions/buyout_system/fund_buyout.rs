@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{
+    AssetConfig, AssetState, AssetStatus, BuyoutState, ProposalAction, QueuedAction, VoteState,
+    VotingMode,
+};
+use crate::{
+    BPS_DENOMINATOR, SEED_ASSET_CONFIG_ACCOUNT, SEED_BUYOUT_STATE_ACCOUNT,
+    SEED_QUEUED_ACTION_ACCOUNT, SEED_STATE_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct FundBuyout<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Box<Account<'info, VoteState>>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Box<Account<'info, QueuedAction>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + BuyoutState::INIT_SPACE,
+        seeds = [SEED_BUYOUT_STATE_ACCOUNT, asset.key().as_ref(), vote_state.key().as_ref()],
+        bump
+    )]
+    pub buyout_state: Box<Account<'info, BuyoutState>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = buyout_state,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub buyout_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = buyer,
+        token::token_program = usdc_token_program,
+    )]
+    pub buyer_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    /// USDC and the shares may live under different token programs
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Runs a passed `Buyout` proposal: the buyer escrows the price of every share in circulation.
+/// The property NFT follows through `complete_buyout` once every share was sold into the buyout.
+pub fn handle_fund_buyout(ctx: Context<FundBuyout>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        !ctx.accounts.asset_state.share_split_in_progress,
+        ErrorCode::ShareSplitInProgress
+    );
+
+    let ProposalAction::Buyout {
+        buyer,
+        price_per_share,
+    } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+    require_keys_eq!(buyer, ctx.accounts.buyer.key(), ErrorCode::InvalidBuyer);
+
+    // A simple majority of the votes cast is not enough to force minority holders out. Voting
+    // weight sits in voter locks until the round ends, so it can't exceed the live supply.
+    let vote_state = &ctx.accounts.vote_state;
+    let total_shares = ctx.accounts.ft_mint.supply;
+    require!(
+        vote_state.voting_mode == VotingMode::TokenWeighted,
+        ErrorCode::InvalidProposalAction
+    );
+    require!(
+        vote_state.yes_weight as u128 * BPS_DENOMINATOR as u128
+            >= ctx.accounts.asset_config.supermajority_bps as u128 * total_shares as u128,
+        ErrorCode::SupermajorityNotReached
+    );
+
+    let deposit =
+        BuyoutState::payment_for(price_per_share, total_shares, ctx.accounts.ft_mint.decimals)?;
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.buyer_usdc_account.to_account_info(),
+        to: ctx.accounts.buyout_vault.to_account_info(),
+        authority: ctx.accounts.buyer.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+    );
+    transfer_checked(cpi_ctx, deposit, ctx.accounts.usdc_mint.decimals)?;
+
+    let buyout_state = &mut ctx.accounts.buyout_state;
+    buyout_state.asset = ctx.accounts.asset.key();
+    buyout_state.buyer = buyer;
+    buyout_state.vote_state = ctx.accounts.vote_state.key();
+    buyout_state.usdc_mint = ctx.accounts.usdc_mint.key();
+    buyout_state.price_per_share = price_per_share;
+    buyout_state.remaining_shares = total_shares;
+    buyout_state.bump = ctx.bumps.buyout_state;
+
+    ctx.accounts.asset_state.status = AssetStatus::BuyoutPending;
+
+    msg!("Buyout funded with {} USDC by {}", deposit, buyer);
+
+    Ok(())
+}
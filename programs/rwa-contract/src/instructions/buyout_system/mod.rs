@@ -0,0 +1,20 @@
+pub mod redeem_property;
+pub use redeem_property::*;
+
+pub mod fund_buyout;
+pub use fund_buyout::*;
+
+pub mod claim_buyout;
+pub use claim_buyout::*;
+
+pub mod claim_treasury_buyout;
+pub use claim_treasury_buyout::*;
+
+pub mod claim_auction_buyout;
+pub use claim_auction_buyout::*;
+
+pub mod complete_buyout;
+pub use complete_buyout::*;
+
+pub mod retire_asset;
+pub use retire_asset::*;
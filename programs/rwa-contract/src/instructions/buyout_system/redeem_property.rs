@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+use mpl_core::instructions::TransferV1CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, AssetStatus};
+use crate::{SEED_STATE_ACCOUNT, SEED_VAULT_OWNER_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct RedeemProperty<'info> {
+    #[account(mut)]
+    pub redeemer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state and by mpl-core
+    #[account(mut)]
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(mut)]
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = redeemer,
+        token::token_program = token_program,
+    )]
+    pub redeemer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA holding the property NFT
+    #[account(
+        seeds = [SEED_VAULT_OWNER_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Portfolio collection of the property NFT, required by mpl-core for assets in one
+    #[account(
+        mut,
+        constraint = asset_state.collection == Some(collection.key()) @ ErrorCode::InvalidPortfolio
+    )]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Burns every share of the property and hands the NFT to the redeemer
+pub fn handle_redeem_property(ctx: Context<RedeemProperty>) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    // Until every holder migrated, the new mint's supply is only part of the shares
+    require!(
+        !ctx.accounts.asset_state.share_split_in_progress,
+        ErrorCode::ShareSplitInProgress
+    );
+
+    // The live supply, so shares burned elsewhere (e.g. buyout claims) can't block redemption and
+    // shares outside the redeemer's account always do
+    let total_shares = ctx.accounts.ft_mint.supply;
+    require!(
+        total_shares > 0 && ctx.accounts.redeemer_token_account.amount == total_shares,
+        ErrorCode::IncompleteShares
+    );
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.ft_mint.to_account_info(),
+                from: ctx.accounts.redeemer_token_account.to_account_info(),
+                authority: ctx.accounts.redeemer.to_account_info(),
+            },
+        ),
+        total_shares,
+    )?;
+
+    let asset_key = ctx.accounts.asset.key();
+    let owner_seeds = &[
+        SEED_VAULT_OWNER_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.owner],
+    ];
+
+    TransferV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(
+            ctx.accounts
+                .collection
+                .as_ref()
+                .map(|collection| collection.as_ref()),
+        )
+        .payer(&ctx.accounts.redeemer.to_account_info())
+        .authority(Some(&ctx.accounts.owner.to_account_info()))
+        .new_owner(&ctx.accounts.redeemer.to_account_info())
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(&[owner_seeds])?;
+
+    ctx.accounts.asset_state.status = AssetStatus::Redeemed;
+
+    msg!(
+        "Property {} redeemed by {}",
+        asset_key,
+        ctx.accounts.redeemer.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::instructions::BurnV1CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, AssetStatus, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    SEED_QUEUED_ACTION_ACCOUNT, SEED_STATE_ACCOUNT, SEED_VAULT_AUTHORITY_ACCOUNT,
+    SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct RetireAsset<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state and by mpl-core
+    #[account(mut)]
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    /// Queued round carrying `RetireAsset`, not needed after a full redemption
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Option<Account<'info, VoteState>>,
+
+    #[account(
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, queued_action.vote_state.as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Option<Account<'info, QueuedAction>>,
+
+    /// CHECK: PDA set as the permanent burn delegate of the asset
+    #[account(
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Portfolio collection of the property NFT, required by mpl-core for assets in one
+    #[account(
+        mut,
+        constraint = asset_state.collection == Some(collection.key()) @ ErrorCode::InvalidPortfolio
+    )]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Burns the property NFT and retires the asset. A redeemed asset can be retired by the owner of
+/// the NFT, an active one only through a passed `RetireAsset` proposal.
+pub fn handle_retire_asset(ctx: Context<RetireAsset>) -> Result<()> {
+    match ctx.accounts.asset_state.status {
+        AssetStatus::Redeemed => {
+            let owner = BaseAssetV1::from_bytes(&ctx.accounts.asset.try_borrow_data()?)?.owner;
+            require_keys_eq!(
+                ctx.accounts.executor.key(),
+                owner,
+                ErrorCode::UnauthorizedRetirement
+            );
+        }
+        AssetStatus::Active => {
+            let (Some(vote_state), Some(queued_action)) = (
+                ctx.accounts.vote_state.as_mut(),
+                ctx.accounts.queued_action.as_ref(),
+            ) else {
+                return Err(ErrorCode::UnauthorizedRetirement.into());
+            };
+            require_keys_eq!(
+                queued_action.vote_state,
+                vote_state.key(),
+                ErrorCode::InvalidProposalAction
+            );
+
+            let action = vote_state.execute_action(queued_action)?;
+            require!(
+                action == ProposalAction::RetireAsset,
+                ErrorCode::InvalidProposalAction
+            );
+        }
+        AssetStatus::BuyoutPending | AssetStatus::Retired => {
+            return Err(ErrorCode::AssetNotActive.into());
+        }
+    }
+
+    let asset_key = ctx.accounts.asset.key();
+    let authority_seeds = &[
+        SEED_VAULT_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    BurnV1CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .asset(&ctx.accounts.asset.to_account_info())
+        .collection(
+            ctx.accounts
+                .collection
+                .as_ref()
+                .map(|collection| collection.as_ref()),
+        )
+        .payer(&ctx.accounts.executor.to_account_info())
+        .authority(Some(&ctx.accounts.authority_pda.to_account_info()))
+        .system_program(Some(&ctx.accounts.system_program.to_account_info()))
+        .invoke_signed(&[authority_seeds])?;
+
+    ctx.accounts.asset_state.status = AssetStatus::Retired;
+
+    msg!("Property {} retired", asset_key);
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    freeze_account, thaw_account, FreezeAccount, Mint, ThawAccount, TokenAccount, TokenInterface,
+};
+
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_FREEZE_AUTHORITY_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT,
+    SEED_STATE_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct HolderFreeze<'info> {
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::token_program = token_program,
+    )]
+    pub holder_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA set as the freeze authority of the share mint
+    #[account(
+        seeds = [SEED_FREEZE_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    /// Queued round carrying the freeze or thaw, not needed when the compliance officer signs
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Option<Account<'info, VoteState>>,
+
+    #[account(
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, queued_action.vote_state.as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Option<Account<'info, QueuedAction>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> HolderFreeze<'info> {
+    /// The compliance officer acts directly, anyone else needs a queued proposal for exactly this action
+    fn authorize(&mut self, expected_action: ProposalAction) -> Result<()> {
+        if self.executor.key() == self.asset_config.compliance_officer {
+            return Ok(());
+        }
+
+        let (Some(vote_state), Some(queued_action)) =
+            (self.vote_state.as_mut(), self.queued_action.as_ref())
+        else {
+            return Err(ErrorCode::UnauthorizedComplianceAction.into());
+        };
+        require_keys_eq!(
+            queued_action.vote_state,
+            vote_state.key(),
+            ErrorCode::InvalidProposalAction
+        );
+
+        let action = vote_state.execute_action(queued_action)?;
+        require!(action == expected_action, ErrorCode::InvalidProposalAction);
+
+        Ok(())
+    }
+}
+
+pub fn handle_freeze_holder(ctx: Context<HolderFreeze>) -> Result<()> {
+    let token_account = ctx.accounts.holder_token_account.key();
+    ctx.accounts
+        .authorize(ProposalAction::FreezeHolder { token_account })?;
+
+    let asset_key = ctx.accounts.asset.key();
+    let freeze_seeds = &[
+        SEED_FREEZE_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.freeze_authority],
+    ];
+    let signer_seeds = &[&freeze_seeds[..]];
+
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.holder_token_account.to_account_info(),
+            mint: ctx.accounts.ft_mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Share account {} frozen", token_account);
+
+    Ok(())
+}
+
+pub fn handle_thaw_holder(ctx: Context<HolderFreeze>) -> Result<()> {
+    let token_account = ctx.accounts.holder_token_account.key();
+    ctx.accounts
+        .authorize(ProposalAction::ThawHolder { token_account })?;
+
+    let asset_key = ctx.accounts.asset.key();
+    let freeze_seeds = &[
+        SEED_FREEZE_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.freeze_authority],
+    ];
+    let signer_seeds = &[&freeze_seeds[..]];
+
+    thaw_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ThawAccount {
+            account: ctx.accounts.holder_token_account.to_account_info(),
+            mint: ctx.accounts.ft_mint.to_account_info(),
+            authority: ctx.accounts.freeze_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!("Share account {} thawed", token_account);
+
+    Ok(())
+}
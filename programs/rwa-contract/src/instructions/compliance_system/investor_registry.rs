@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use compliance_hook::cpi::accounts::{AddInvestor, RemoveInvestor};
+use compliance_hook::program::ComplianceHook;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_COMPLIANCE_AUTHORITY_ACCOUNT,
+    SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT, SEED_VOTER_ESCROW_ACCOUNT,
+};
+
+/// Program accounts that hold shares on behalf of holders and must be able to receive them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProgramShareAccount {
+    AuctionVault {
+        auction_creator: Pubkey,
+        auction_id: u64,
+    },
+    Treasury,
+    VoterEscrow,
+}
+
+#[derive(Accounts)]
+pub struct InvestorRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA set as the authority of the share mint's investor registry
+    #[account(
+        seeds = [SEED_COMPLIANCE_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub compliance_authority: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the compliance hook program
+    pub compliance_config: UncheckedAccount<'info>,
+
+    /// CHECK: created, closed and validated by the compliance hook program
+    #[account(mut)]
+    pub investor_entry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub compliance_hook_program: Program<'info, ComplianceHook>,
+}
+
+impl<'info> InvestorRegistry<'info> {
+    fn add_investor(&self, wallet: Pubkey, compliance_authority_bump: u8) -> Result<()> {
+        let asset_key = self.asset.key();
+        let authority_seeds = &[
+            SEED_COMPLIANCE_AUTHORITY_ACCOUNT,
+            asset_key.as_ref(),
+            &[compliance_authority_bump],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        compliance_hook::cpi::add_investor(
+            CpiContext::new_with_signer(
+                self.compliance_hook_program.to_account_info(),
+                AddInvestor {
+                    payer: self.payer.to_account_info(),
+                    authority: self.compliance_authority.to_account_info(),
+                    mint: self.ft_mint.to_account_info(),
+                    compliance_config: self.compliance_config.to_account_info(),
+                    investor_entry: self.investor_entry.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            wallet,
+        )
+    }
+}
+
+/// Lets `wallet` receive shares, only the asset's compliance officer can add investors
+pub fn handle_register_investor(ctx: Context<InvestorRegistry>, wallet: Pubkey) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.payer.key(),
+        ctx.accounts.asset_config.compliance_officer,
+        ErrorCode::UnauthorizedComplianceAction
+    );
+
+    ctx.accounts
+        .add_investor(wallet, ctx.bumps.compliance_authority)?;
+
+    msg!("Investor {} registered", wallet);
+
+    Ok(())
+}
+
+/// Removes a wallet from the registry and refunds the entry's rent to the compliance officer
+pub fn handle_unregister_investor(ctx: Context<InvestorRegistry>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.payer.key(),
+        ctx.accounts.asset_config.compliance_officer,
+        ErrorCode::UnauthorizedComplianceAction
+    );
+
+    let asset_key = ctx.accounts.asset.key();
+    let authority_seeds = &[
+        SEED_COMPLIANCE_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.compliance_authority],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    compliance_hook::cpi::remove_investor(CpiContext::new_with_signer(
+        ctx.accounts.compliance_hook_program.to_account_info(),
+        RemoveInvestor {
+            authority: ctx.accounts.compliance_authority.to_account_info(),
+            rent_receiver: ctx.accounts.payer.to_account_info(),
+            mint: ctx.accounts.ft_mint.to_account_info(),
+            compliance_config: ctx.accounts.compliance_config.to_account_info(),
+            investor_entry: ctx.accounts.investor_entry.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    msg!(
+        "Investor entry {} removed",
+        ctx.accounts.investor_entry.key()
+    );
+
+    Ok(())
+}
+
+/// Registers a program-owned share account so auctions, the treasury and voter locks work with
+/// compliance-restricted shares. Anyone can call it, since only this program controls the PDAs.
+pub fn handle_register_program_account(
+    ctx: Context<InvestorRegistry>,
+    account: ProgramShareAccount,
+) -> Result<()> {
+    let asset_key = ctx.accounts.asset.key();
+    let ft_mint_key = ctx.accounts.ft_mint.key();
+    let (wallet, _) = match account {
+        ProgramShareAccount::AuctionVault {
+            auction_creator,
+            auction_id,
+        } => Pubkey::find_program_address(
+            &[
+                SEED_AUCTION_VAULT_ACCOUNT,
+                auction_creator.as_ref(),
+                auction_id.to_le_bytes().as_ref(),
+            ],
+            ctx.program_id,
+        ),
+        ProgramShareAccount::Treasury => Pubkey::find_program_address(
+            &[SEED_TREASURY_ACCOUNT, asset_key.as_ref()],
+            ctx.program_id,
+        ),
+        ProgramShareAccount::VoterEscrow => Pubkey::find_program_address(
+            &[SEED_VOTER_ESCROW_ACCOUNT, ft_mint_key.as_ref()],
+            ctx.program_id,
+        ),
+    };
+
+    ctx.accounts
+        .add_investor(wallet, ctx.bumps.compliance_authority)?;
+
+    msg!("Program account {} registered", wallet);
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+pub mod holder_freeze;
+pub use holder_freeze::*;
+
+pub mod investor_registry;
+pub use investor_registry::*;
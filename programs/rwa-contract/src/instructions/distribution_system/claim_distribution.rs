@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::state::{Distribution, DistributionClaim};
+use crate::{SEED_DISTRIBUTION_ACCOUNT, SEED_DISTRIBUTION_CLAIM_ACCOUNT};
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct ClaimDistribution<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        seeds = [SEED_DISTRIBUTION_ACCOUNT, distribution.asset.as_ref(), distribution.period.to_le_bytes().as_ref()],
+        bump = distribution.bump,
+    )]
+    pub distribution: Box<Account<'info, Distribution>>,
+
+    /// Fails if the snapshot leaf was already claimed
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + DistributionClaim::INIT_SPACE,
+        seeds = [SEED_DISTRIBUTION_CLAIM_ACCOUNT, distribution.key().as_ref(), index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distribution_claim: Box<Account<'info, DistributionClaim>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = distribution,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub holder_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Pays the holder their pro-rata part of a period's rental income for the shares they held at
+/// leaf `index` of the distribution's snapshot, once per leaf
+pub fn handle_claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    index: u32,
+    shares: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(shares > 0, ErrorCode::NoShares);
+    require!(
+        Clock::get()?.unix_timestamp < ctx.accounts.distribution.claim_end_time,
+        ErrorCode::DistributionClaimsClosed
+    );
+    require!(
+        ctx.accounts.distribution.verify_snapshot(
+            &proof,
+            index,
+            &ctx.accounts.holder.key(),
+            shares
+        ),
+        ErrorCode::InvalidMerkleProof
+    );
+
+    let payout = ctx.accounts.distribution.payout_for(shares)?;
+    require!(payout > 0, ErrorCode::NothingToClaim);
+
+    let distribution = &ctx.accounts.distribution;
+    let period_bytes = distribution.period.to_le_bytes();
+    let distribution_seeds = &[
+        SEED_DISTRIBUTION_ACCOUNT,
+        distribution.asset.as_ref(),
+        period_bytes.as_ref(),
+        &[distribution.bump],
+    ];
+    let signer_seeds = &[&distribution_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.distribution_vault.to_account_info(),
+        to: ctx.accounts.holder_usdc_account.to_account_info(),
+        authority: ctx.accounts.distribution.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, payout, ctx.accounts.usdc_mint.decimals)?;
+
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.record_claim(shares, payout)?;
+
+    let distribution_claim = &mut ctx.accounts.distribution_claim;
+    distribution_claim.distribution = distribution.key();
+    distribution_claim.index = index;
+    distribution_claim.holder = ctx.accounts.holder.key();
+    distribution_claim.shares = shares;
+    distribution_claim.amount = payout;
+    distribution_claim.bump = ctx.bumps.distribution_claim;
+
+    msg!(
+        "Paid {} USDC of period {} income to {}",
+        payout,
+        distribution.period,
+        ctx.accounts.holder.key()
+    );
+
+    Ok(())
+}
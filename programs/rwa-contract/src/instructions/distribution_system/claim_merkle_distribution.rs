@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::state::{merkle_leaf_hash, MerkleClaims, MerkleDistribution};
+use crate::{SEED_MERKLE_CLAIMS_ACCOUNT, SEED_MERKLE_DISTRIBUTION_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ClaimMerkleDistribution<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        seeds = [SEED_MERKLE_DISTRIBUTION_ACCOUNT, merkle_distribution.asset.as_ref(), merkle_distribution.epoch.to_le_bytes().as_ref()],
+        bump = merkle_distribution.bump,
+    )]
+    pub merkle_distribution: Box<Account<'info, MerkleDistribution>>,
+
+    #[account(
+        mut,
+        has_one = merkle_distribution,
+        seeds = [SEED_MERKLE_CLAIMS_ACCOUNT, merkle_distribution.key().as_ref()],
+        bump,
+    )]
+    pub merkle_claims: Box<Account<'info, MerkleClaims>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = merkle_distribution,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub holder_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Pays the holder the amount allocated to them at `index` of the distribution tree
+pub fn handle_claim_merkle_distribution(
+    ctx: Context<ClaimMerkleDistribution>,
+    index: u32,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let merkle_distribution = &ctx.accounts.merkle_distribution;
+    require!(
+        index < merkle_distribution.num_leaves,
+        ErrorCode::InvalidMerkleProof
+    );
+
+    let leaf = merkle_leaf_hash(index, &ctx.accounts.holder.key(), amount);
+    require!(
+        merkle_distribution.verify(&proof, leaf),
+        ErrorCode::InvalidMerkleProof
+    );
+
+    ctx.accounts.merkle_claims.set_claimed(index)?;
+
+    // A root committing to more than was funded can't drain other epochs' vaults
+    let claimed_amount = merkle_distribution
+        .claimed_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    require!(
+        claimed_amount <= merkle_distribution.total_amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+
+    let epoch_bytes = merkle_distribution.epoch.to_le_bytes();
+    let distribution_seeds = &[
+        SEED_MERKLE_DISTRIBUTION_ACCOUNT,
+        merkle_distribution.asset.as_ref(),
+        epoch_bytes.as_ref(),
+        &[merkle_distribution.bump],
+    ];
+    let signer_seeds = &[&distribution_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.distribution_vault.to_account_info(),
+        to: ctx.accounts.holder_usdc_account.to_account_info(),
+        authority: ctx.accounts.merkle_distribution.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    let merkle_distribution = &mut ctx.accounts.merkle_distribution;
+    merkle_distribution.claimed_amount = claimed_amount;
+
+    msg!(
+        "Paid {} USDC of merkle epoch {} to {}",
+        amount,
+        merkle_distribution.epoch,
+        ctx.accounts.holder.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState, AssetStatus, MerkleClaims, MerkleDistribution};
+use crate::{
+    MAX_MERKLE_DISTRIBUTION_LEAVES, SEED_ASSET_CONFIG_ACCOUNT, SEED_MERKLE_CLAIMS_ACCOUNT,
+    SEED_MERKLE_DISTRIBUTION_ACCOUNT, SEED_STATE_ACCOUNT,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateMerkleDistributionArgs {
+    pub epoch: u64,
+    pub merkle_root: [u8; 32],
+    pub num_leaves: u32,
+    pub total_amount: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateMerkleDistributionArgs)]
+pub struct CreateMerkleDistribution<'info> {
+    #[account(mut)]
+    pub distributor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        has_one = asset,
+        constraint = asset_config.asset_manager == distributor.key() @ ErrorCode::UnauthorizedDistributionDeposit,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = distributor,
+        space = 8 + MerkleDistribution::INIT_SPACE,
+        seeds = [SEED_MERKLE_DISTRIBUTION_ACCOUNT, asset.key().as_ref(), args.epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub merkle_distribution: Box<Account<'info, MerkleDistribution>>,
+
+    #[account(
+        init,
+        payer = distributor,
+        space = MerkleClaims::space(args.num_leaves.min(MAX_MERKLE_DISTRIBUTION_LEAVES)),
+        seeds = [SEED_MERKLE_CLAIMS_ACCOUNT, merkle_distribution.key().as_ref()],
+        bump
+    )]
+    pub merkle_claims: Box<Account<'info, MerkleClaims>>,
+
+    #[account(
+        init,
+        payer = distributor,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = merkle_distribution,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = distributor,
+        token::token_program = usdc_token_program,
+    )]
+    pub distributor_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Publishes the merkle root of an epoch's (holder, amount) allocations and funds it
+pub fn handle_create_merkle_distribution(
+    ctx: Context<CreateMerkleDistribution>,
+    args: CreateMerkleDistributionArgs,
+) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        args.total_amount > 0
+            && args.num_leaves > 0
+            && args.num_leaves <= MAX_MERKLE_DISTRIBUTION_LEAVES,
+        ErrorCode::InvalidMerkleDistribution
+    );
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.distributor_usdc_account.to_account_info(),
+        to: ctx.accounts.distribution_vault.to_account_info(),
+        authority: ctx.accounts.distributor.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+    );
+    transfer_checked(cpi_ctx, args.total_amount, ctx.accounts.usdc_mint.decimals)?;
+
+    let merkle_distribution = &mut ctx.accounts.merkle_distribution;
+    merkle_distribution.asset = ctx.accounts.asset.key();
+    merkle_distribution.distributor = ctx.accounts.distributor.key();
+    merkle_distribution.usdc_mint = ctx.accounts.usdc_mint.key();
+    merkle_distribution.epoch = args.epoch;
+    merkle_distribution.merkle_root = args.merkle_root;
+    merkle_distribution.num_leaves = args.num_leaves;
+    merkle_distribution.total_amount = args.total_amount;
+    merkle_distribution.claimed_amount = 0;
+    merkle_distribution.bump = ctx.bumps.merkle_distribution;
+
+    let merkle_claims = &mut ctx.accounts.merkle_claims;
+    merkle_claims.merkle_distribution = merkle_distribution.key();
+    merkle_claims.bitmap = vec![0; (args.num_leaves as usize).div_ceil(8)];
+
+    msg!(
+        "Funded merkle distribution epoch {} with {} USDC for {} holders",
+        args.epoch,
+        args.total_amount,
+        args.num_leaves
+    );
+
+    Ok(())
+}
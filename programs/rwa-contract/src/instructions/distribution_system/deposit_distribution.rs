@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState, AssetStatus, Distribution};
+use crate::{
+    DISTRIBUTION_CLAIM_WINDOW, SEED_ASSET_CONFIG_ACCOUNT, SEED_DISTRIBUTION_ACCOUNT,
+    SEED_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct DepositDistribution<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        has_one = asset,
+        constraint = asset_config.asset_manager == depositor.key() @ ErrorCode::UnauthorizedDistributionDeposit,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + Distribution::INIT_SPACE,
+        seeds = [SEED_DISTRIBUTION_ACCOUNT, asset.key().as_ref(), period.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distribution: Box<Account<'info, Distribution>>,
+
+    #[account(
+        init,
+        payer = depositor,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = distribution,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = depositor,
+        token::token_program = usdc_token_program,
+    )]
+    pub depositor_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Escrows a period's rental income for the holders to claim pro rata to their balances in
+/// `snapshot_root`, taken by the asset manager when depositing
+pub fn handle_deposit_distribution(
+    ctx: Context<DepositDistribution>,
+    period: u64,
+    amount: u64,
+    snapshot_root: [u8; 32],
+) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        !ctx.accounts.asset_state.share_split_in_progress,
+        ErrorCode::ShareSplitInProgress
+    );
+    require!(amount > 0, ErrorCode::InvalidDistributionAmount);
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.depositor_usdc_account.to_account_info(),
+        to: ctx.accounts.distribution_vault.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+    );
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    ctx.accounts.distribution.initialize(
+        ctx.accounts.asset.key(),
+        ctx.accounts.asset_state.ft_mint,
+        ctx.accounts.usdc_mint.key(),
+        ctx.accounts.depositor.key(),
+        period,
+        amount,
+        ctx.accounts.asset_state.total_shares,
+        snapshot_root,
+        Clock::get()?
+            .unix_timestamp
+            .checked_add(DISTRIBUTION_CLAIM_WINDOW)
+            .ok_or(ErrorCode::Overflow)?,
+        ctx.bumps.distribution,
+    )?;
+
+    msg!(
+        "Deposited {} USDC of rental income for period {}",
+        amount,
+        period
+    );
+
+    Ok(())
+}
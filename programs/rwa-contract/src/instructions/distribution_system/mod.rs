@@ -0,0 +1,14 @@
+pub mod deposit_distribution;
+pub use deposit_distribution::*;
+
+pub mod claim_distribution;
+pub use claim_distribution::*;
+
+pub mod create_merkle_distribution;
+pub use create_merkle_distribution::*;
+
+pub mod claim_merkle_distribution;
+pub use claim_merkle_distribution::*;
+
+pub mod sweep_distribution;
+pub use sweep_distribution::*;
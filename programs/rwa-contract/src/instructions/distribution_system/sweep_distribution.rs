@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::error::ErrorCode;
+use crate::state::Distribution;
+use crate::{SEED_DISTRIBUTION_ACCOUNT, SEED_TREASURY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct SweepDistribution<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = usdc_mint,
+        seeds = [SEED_DISTRIBUTION_ACCOUNT, distribution.asset.as_ref(), distribution.period.to_le_bytes().as_ref()],
+        bump = distribution.bump,
+    )]
+    pub distribution: Box<Account<'info, Distribution>>,
+
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = distribution,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, distribution.asset.as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = usdc_token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub usdc_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Moves the income nobody claimed within the claim window back to the asset treasury.
+/// Anyone can call it.
+pub fn handle_sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+    let distribution = &ctx.accounts.distribution;
+    require!(
+        Clock::get()?.unix_timestamp >= distribution.claim_end_time,
+        ErrorCode::DistributionClaimsOpen
+    );
+
+    let unclaimed = ctx.accounts.distribution_vault.amount;
+    require!(unclaimed > 0, ErrorCode::NothingToClaim);
+
+    let period_bytes = distribution.period.to_le_bytes();
+    let distribution_seeds = &[
+        SEED_DISTRIBUTION_ACCOUNT,
+        distribution.asset.as_ref(),
+        period_bytes.as_ref(),
+        &[distribution.bump],
+    ];
+    let signer_seeds = &[&distribution_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.distribution_vault.to_account_info(),
+        to: ctx.accounts.treasury_usdc_account.to_account_info(),
+        authority: ctx.accounts.distribution.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.usdc_token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, unclaimed, ctx.accounts.usdc_mint.decimals)?;
+
+    // Nothing is left to claim once swept
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.claimed = distribution.amount;
+
+    msg!(
+        "Swept {} unclaimed USDC of period {} to the treasury",
+        unclaimed,
+        distribution.period
+    );
+
+    Ok(())
+}
@@ -9,3 +9,18 @@ pub use voting_system::*;
 
 pub mod auction_system;
 pub use auction_system::*;
+
+pub mod proposal_system;
+pub use proposal_system::*;
+
+pub mod compliance_system;
+pub use compliance_system::*;
+
+pub mod buyout_system;
+pub use buyout_system::*;
+
+pub mod distribution_system;
+pub use distribution_system::*;
+
+pub mod treasury_system;
+pub use treasury_system::*;
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{
+    AssetConfig, AssetState, ProposalAction, ProposalStatus, QueuedAction, VoteState,
+};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT, SEED_STATE_ACCOUNT,
+    SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct CancelQueuedAction<'info> {
+    pub canceler: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    /// Passed round carrying a `CancelQueuedAction` for `vote_state`, not needed when the guardian signs
+    #[account(mut, has_one = asset)]
+    pub counter_vote_state: Option<Account<'info, VoteState>>,
+}
+
+pub fn handle_cancel_queued_action(ctx: Context<CancelQueuedAction>) -> Result<()> {
+    require!(
+        ctx.accounts.vote_state.status == ProposalStatus::Queued,
+        ErrorCode::ProposalNotQueued
+    );
+
+    let is_guardian = ctx.accounts.canceler.key() == ctx.accounts.asset_config.guardian;
+    if !is_guardian {
+        let target = ctx.accounts.vote_state.key();
+        let counter_vote_state = ctx
+            .accounts
+            .counter_vote_state
+            .as_mut()
+            .ok_or(ErrorCode::UnauthorizedCancellation)?;
+
+        require!(
+            counter_vote_state.status == ProposalStatus::Passed,
+            ErrorCode::ProposalNotPassed
+        );
+        require!(
+            counter_vote_state.action
+                == Some(ProposalAction::CancelQueuedAction { vote_state: target }),
+            ErrorCode::UnauthorizedCancellation
+        );
+        counter_vote_state.status = ProposalStatus::Executed;
+    }
+
+    if let Some(ProposalAction::TreasuryPayment { amount, .. }) = ctx.accounts.vote_state.action {
+        let asset_state = &mut ctx.accounts.asset_state;
+        asset_state.committed_usdc = asset_state.committed_usdc.saturating_sub(amount);
+    }
+
+    ctx.accounts.vote_state.status = ProposalStatus::Canceled;
+
+    msg!(
+        "Queued action of vote round {} canceled",
+        ctx.accounts.vote_state.vote_round
+    );
+
+    Ok(())
+}
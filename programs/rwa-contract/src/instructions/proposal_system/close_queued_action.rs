@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{ProposalStatus, QueuedAction, VoteState};
+use crate::{SEED_QUEUED_ACTION_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct CloseQueuedAction<'info> {
+    /// CHECK: Receives the rent, validated through queued_action has_one constraint
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: Asset account is validated through vote_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = vote_state,
+        has_one = payer,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+}
+
+/// Returns the rent of a queued action to whoever queued it once the action ran or was canceled.
+/// Anyone can call it, the queued action is only read while its round is queued.
+pub fn handle_close_queued_action(ctx: Context<CloseQueuedAction>) -> Result<()> {
+    require!(
+        matches!(
+            ctx.accounts.vote_state.status,
+            ProposalStatus::Executed | ProposalStatus::Canceled
+        ),
+        ErrorCode::ProposalStillQueued
+    );
+
+    Ok(())
+}
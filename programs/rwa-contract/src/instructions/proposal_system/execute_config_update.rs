@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    BPS_DENOMINATOR, MIN_SUPERMAJORITY_BPS, SEED_ASSET_CONFIG_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT,
+    SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteConfigUpdate<'info> {
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+}
+
+pub fn handle_execute_config_update(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+    let ProposalAction::UpdateConfig {
+        timelock_delay,
+        guardian,
+        supermajority_bps,
+        proposal_threshold_bps,
+        proposal_deposit,
+        quorum_bps,
+        tag_along_threshold_bps,
+        tag_along_window,
+    } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+
+    let asset_config = &mut ctx.accounts.asset_config;
+    if let Some(timelock_delay) = timelock_delay {
+        require!(timelock_delay >= 0, ErrorCode::InvalidTimelockDelay);
+        asset_config.timelock_delay = timelock_delay;
+    }
+    if let Some(guardian) = guardian {
+        asset_config.guardian = guardian;
+    }
+    if let Some(supermajority_bps) = supermajority_bps {
+        require!(
+            supermajority_bps >= MIN_SUPERMAJORITY_BPS
+                && supermajority_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidSupermajority
+        );
+        asset_config.supermajority_bps = supermajority_bps;
+    }
+    if let Some(proposal_threshold_bps) = proposal_threshold_bps {
+        require!(
+            proposal_threshold_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidBasisPoints
+        );
+        asset_config.proposal_threshold_bps = proposal_threshold_bps;
+    }
+    if let Some(proposal_deposit) = proposal_deposit {
+        // Forfeited deposits must be able to fund a fresh treasury account
+        require!(
+            proposal_deposit == 0 || proposal_deposit >= Rent::get()?.minimum_balance(0),
+            ErrorCode::InvalidProposalDeposit
+        );
+        asset_config.proposal_deposit = proposal_deposit;
+    }
+    if let Some(quorum_bps) = quorum_bps {
+        require!(
+            quorum_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidBasisPoints
+        );
+        asset_config.quorum_bps = quorum_bps;
+    }
+    if let Some(tag_along_threshold_bps) = tag_along_threshold_bps {
+        require!(
+            tag_along_threshold_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidBasisPoints
+        );
+        asset_config.tag_along_threshold_bps = tag_along_threshold_bps;
+    }
+    if let Some(tag_along_window) = tag_along_window {
+        require!(tag_along_window >= 0, ErrorCode::InvalidTagAlongWindow);
+        asset_config.tag_along_window = tag_along_window;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_hook::TransferHook;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{
+    find_mint_account_size, initialize_mint2, metadata_pointer_initialize, set_authority,
+    spl_token_metadata_interface::state::TokenMetadata, transfer_hook_initialize, InitializeMint2,
+    MetadataPointerInitialize, Mint, SetAuthority, TokenInterface, TransferHookInitialize,
+};
+use compliance_hook::cpi::accounts::InitializeExtraAccountMetaList;
+use compliance_hook::program::ComplianceHook;
+
+use crate::error::ErrorCode;
+use crate::instructions::token_system::{
+    assign_freeze_authority, compliance_authority_address, initialize_share_metadata,
+    ShareMetadataArgs,
+};
+use crate::state::{AssetState, AssetStatus, ProposalAction, QueuedAction, ShareSplit, VoteState};
+use crate::{
+    SEED_QUEUED_ACTION_ACCOUNT, SEED_SHARE_SPLIT_ACCOUNT, SEED_STATE_ACCOUNT,
+    SEED_VAULT_AUTHORITY_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteShareSplit<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state PDA
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = ft_mint,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    /// Current share mint, replaced by `new_mint`
+    #[account(mint::token_program = token_program)]
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// New share mint, created with the transfer hook and metadata of `ft_mint`. The split PDA
+    /// mints the new shares as holders migrate.
+    #[account(mut)]
+    pub new_mint: Signer<'info>,
+
+    /// CHECK: Update authority of the share metadata
+    #[account(
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub authority_pda: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + ShareSplit::INIT_SPACE,
+        seeds = [SEED_SHARE_SPLIT_ACCOUNT, ft_mint.key().as_ref()],
+        bump
+    )]
+    pub share_split: Box<Account<'info, ShareSplit>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Box<Account<'info, VoteState>>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Box<Account<'info, QueuedAction>>,
+
+    /// CHECK: created and validated by the compliance hook program, compliance-restricted shares only
+    #[account(mut)]
+    pub extra_account_meta_list: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: created and validated by the compliance hook program, compliance-restricted shares only
+    #[account(mut)]
+    pub compliance_config: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub compliance_hook_program: Option<Program<'info, ComplianceHook>>,
+}
+
+/// Runs a passed `ShareSplit` proposal: the asset switches to a new share mint that holders
+/// migrate to with `claim_share_split`. Auction vaults and vote weights are counted in the
+/// current shares, so the split waits until no auction or vote round is open.
+///
+/// The new mint keeps the transfer hook, metadata and freeze authority of the current one.
+/// Its investor registry starts empty: claims mint without going through the hook, but
+/// investors and program accounts must be registered again before the new shares can move.
+pub fn handle_execute_share_split(ctx: Context<ExecuteShareSplit>) -> Result<()> {
+    let asset_state = &ctx.accounts.asset_state;
+    require!(
+        asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        !asset_state.share_split_in_progress,
+        ErrorCode::ShareSplitInProgress
+    );
+    require!(
+        asset_state.open_auctions == 0 && asset_state.open_vote_rounds == 0,
+        ErrorCode::ShareSplitBlocked
+    );
+
+    let ProposalAction::ShareSplit {
+        numerator,
+        denominator,
+    } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+    require!(
+        numerator > 0 && denominator > 0,
+        ErrorCode::InvalidSplitRatio
+    );
+
+    let (hook_program, metadata) = share_mint_extensions(&ctx.accounts.ft_mint.to_account_info())?;
+    if let Some(hook_program) = hook_program {
+        require_keys_eq!(
+            hook_program,
+            compliance_hook::ID,
+            ErrorCode::UnsupportedTransferHook
+        );
+    }
+
+    let executor = ctx.accounts.executor.to_account_info();
+    let new_mint = ctx.accounts.new_mint.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let asset_key = ctx.accounts.asset.key();
+
+    let mut extensions = Vec::new();
+    if hook_program.is_some() {
+        extensions.push(ExtensionType::TransferHook);
+    }
+    if metadata.is_some() {
+        extensions.push(ExtensionType::MetadataPointer);
+    }
+    let space = find_mint_account_size(Some(&extensions))?;
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: executor.clone(),
+                to: new_mint.clone(),
+            },
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        token_program.key,
+    )?;
+
+    // Same as `create_compliant_fungible_token`, the hook gets no authority
+    if hook_program.is_some() {
+        transfer_hook_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                TransferHookInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: new_mint.clone(),
+                },
+            ),
+            None,
+            hook_program,
+        )?;
+    }
+    if metadata.is_some() {
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.clone(),
+                    mint: new_mint.clone(),
+                },
+            ),
+            Some(ctx.accounts.authority_pda.key()),
+            Some(new_mint.key()),
+        )?;
+    }
+
+    // The executor holds both authorities until the extensions are set up
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 {
+                mint: new_mint.clone(),
+            },
+        ),
+        ctx.accounts.ft_mint.decimals,
+        executor.key,
+        Some(executor.key),
+    )?;
+
+    if let Some(metadata) = metadata {
+        initialize_share_metadata(
+            &executor,
+            &new_mint,
+            &ctx.accounts.authority_pda.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &token_program,
+            &asset_key,
+            ctx.bumps.authority_pda,
+            ShareMetadataArgs {
+                name: metadata.name,
+                symbol: metadata.symbol,
+                uri: metadata.uri,
+            },
+        )?;
+    }
+
+    // Shares under regulatory holds stay under them after the split
+    let old_freeze_authority: Option<Pubkey> = ctx.accounts.ft_mint.freeze_authority.into();
+    assign_freeze_authority(
+        &executor,
+        &new_mint,
+        &token_program,
+        old_freeze_authority.map(|_| asset_key),
+    )?;
+
+    if hook_program.is_some() {
+        let (Some(extra_account_meta_list), Some(compliance_config), Some(compliance_hook_program)) = (
+            ctx.accounts.extra_account_meta_list.as_ref(),
+            ctx.accounts.compliance_config.as_ref(),
+            ctx.accounts.compliance_hook_program.as_ref(),
+        ) else {
+            return Err(ErrorCode::MissingHookAccounts.into());
+        };
+
+        compliance_hook::cpi::initialize_extra_account_meta_list(
+            CpiContext::new(
+                compliance_hook_program.to_account_info(),
+                InitializeExtraAccountMetaList {
+                    payer: executor.clone(),
+                    mint: new_mint.clone(),
+                    extra_account_meta_list: extra_account_meta_list.to_account_info(),
+                    compliance_config: compliance_config.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+            ),
+            compliance_authority_address(&asset_key),
+        )?;
+    }
+
+    set_authority(
+        CpiContext::new(
+            token_program.clone(),
+            SetAuthority {
+                account_or_mint: new_mint.clone(),
+                current_authority: executor.clone(),
+            },
+        ),
+        AuthorityType::MintTokens,
+        Some(ctx.accounts.share_split.key()),
+    )?;
+
+    let old_supply = ctx.accounts.ft_mint.supply;
+    let share_split = &mut ctx.accounts.share_split;
+    share_split.asset = asset_key;
+    share_split.old_mint = ctx.accounts.ft_mint.key();
+    share_split.new_mint = new_mint.key();
+    share_split.numerator = numerator;
+    share_split.denominator = denominator;
+    share_split.remaining_old_shares = old_supply;
+    share_split.expected_new_shares = share_split.convert(old_supply)?;
+    share_split.minted_new_shares = 0;
+    share_split.bump = ctx.bumps.share_split;
+
+    // Claims and the dust minted by `finish_share_split` add up to exactly the converted supply
+    let expected_new_shares = share_split.expected_new_shares;
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.ft_mint = new_mint.key();
+    asset_state.total_shares = expected_new_shares;
+    asset_state.share_split_in_progress = true;
+
+    msg!(
+        "Shares of {} split {}:{} into mint {}",
+        asset_state.asset,
+        numerator,
+        denominator,
+        asset_state.ft_mint
+    );
+
+    Ok(())
+}
+
+/// Transfer hook program and on-mint metadata of a share mint, when it has them
+fn share_mint_extensions(mint: &AccountInfo) -> Result<(Option<Pubkey>, Option<TokenMetadata>)> {
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    let hook_program = mint_state
+        .get_extension::<TransferHook>()
+        .ok()
+        .and_then(|transfer_hook| transfer_hook.program_id.into());
+    let metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .ok();
+
+    Ok((hook_program, metadata))
+}
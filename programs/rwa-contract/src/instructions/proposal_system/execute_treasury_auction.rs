@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::instructions::{create_share_vault, transfer_shares};
+use crate::state::{AssetState, AuctionState, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    SEED_AUCTION_STATE_ACCOUNT, SEED_AUCTION_VAULT_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT,
+    SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteTreasuryAuction<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state PDA
+    pub asset: AccountInfo<'info>,
+
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// USDC mint account for bids
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = ft_mint,
+        has_one = asset,
+        constraint = !asset_state.share_split_in_progress @ ErrorCode::ShareSplitInProgress,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Box<Account<'info, VoteState>>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Box<Account<'info, QueuedAction>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = ft_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    // The treasury acts as auction creator, so bids settle into its USDC account. The round number
+    // is unique per asset and serves as auction id, so the treasury can hold several auctions.
+    /// CHECK: Share vault owning itself, created by the handler
+    #[account(
+        mut,
+        seeds = [SEED_AUCTION_VAULT_ACCOUNT, treasury.key().as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + AuctionState::INIT_SPACE,
+        seeds = [SEED_AUCTION_STATE_ACCOUNT, treasury.key().as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub auction_state: Box<Account<'info, AuctionState>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Compliance-restricted shares need the transfer hook accounts in `remaining_accounts`, and the
+/// treasury's auction vault for this round registered through `register_program_account`
+pub fn handle_execute_treasury_auction<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteTreasuryAuction<'info>>,
+) -> Result<()> {
+    let ProposalAction::TreasuryAuction {
+        amount,
+        auction_end_time,
+    } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+
+    // The timelock may have run past the end time voters approved
+    require!(
+        auction_end_time > Clock::get()?.unix_timestamp,
+        ErrorCode::InvalidAuctionEndTime
+    );
+    require!(
+        ctx.accounts.treasury_token_account.amount >= amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+
+    let asset_key = ctx.accounts.asset.key();
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+    let signer_seeds = &[&treasury_seeds[..]];
+
+    let treasury_key = ctx.accounts.treasury.key();
+    let vote_round_bytes = ctx.accounts.vote_state.vote_round.to_le_bytes();
+    let vault_seeds = &[
+        SEED_AUCTION_VAULT_ACCOUNT,
+        treasury_key.as_ref(),
+        vote_round_bytes.as_ref(),
+        &[ctx.bumps.auction_vault],
+    ];
+    create_share_vault(
+        &ctx.accounts.executor.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[&vault_seeds[..]],
+    )?;
+
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.treasury_token_account.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.auction_vault.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        signer_seeds,
+    )?;
+
+    let auction_state = &mut ctx.accounts.auction_state;
+    auction_state.asset = asset_key;
+    auction_state.auction_creator = ctx.accounts.treasury.key();
+    auction_state.auction_id = ctx.accounts.vote_state.vote_round;
+    auction_state.ft_mint = ctx.accounts.ft_mint.key();
+    auction_state.bid_token_mint = ctx.accounts.usdc_mint.key();
+    auction_state.is_active = true;
+    auction_state.highest_bid = 0;
+    auction_state.highest_bidder = Pubkey::default();
+    auction_state.auction_end_time = auction_end_time;
+    // Treasury sales are decided by governance, other holders can't tag along
+    auction_state.total_amount = amount;
+    auction_state.join_end_time = 0;
+    auction_state.contributor_count = 0;
+    auction_state.rofr_end_time = 0;
+    auction_state.asking_price = 0;
+    auction_state.bump = ctx.bumps.auction_state;
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_auctions = asset_state
+        .open_auctions
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    Ok(())
+}
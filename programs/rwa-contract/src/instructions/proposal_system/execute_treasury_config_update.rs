@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    BPS_DENOMINATOR, SEED_ASSET_CONFIG_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteTreasuryConfigUpdate<'info> {
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+}
+
+/// Applies the treasury settings only the holders can change, so the config authority can't
+/// raise the manager's spending or redirect the waterfall on its own
+pub fn handle_execute_treasury_config_update(
+    ctx: Context<ExecuteTreasuryConfigUpdate>,
+) -> Result<()> {
+    let ProposalAction::UpdateTreasuryConfig {
+        asset_manager,
+        manager_allowance,
+        allowance_period,
+        reserve_target,
+        management_fee_bps,
+        debt_service_amount,
+        debt_service_recipient,
+    } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+
+    let asset_config = &mut ctx.accounts.asset_config;
+    if let Some(asset_manager) = asset_manager {
+        asset_config.asset_manager = asset_manager;
+    }
+    if let Some(manager_allowance) = manager_allowance {
+        asset_config.manager_allowance = manager_allowance;
+    }
+    if let Some(allowance_period) = allowance_period {
+        require!(allowance_period > 0, ErrorCode::InvalidAllowancePeriod);
+        asset_config.allowance_period = allowance_period;
+    }
+    if let Some(reserve_target) = reserve_target {
+        asset_config.reserve_target = reserve_target;
+    }
+    if let Some(management_fee_bps) = management_fee_bps {
+        require!(
+            management_fee_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidBasisPoints
+        );
+        asset_config.management_fee_bps = management_fee_bps;
+    }
+    if let Some(debt_service_amount) = debt_service_amount {
+        asset_config.debt_service_amount = debt_service_amount;
+    }
+    if let Some(debt_service_recipient) = debt_service_recipient {
+        asset_config.debt_service_recipient = debt_service_recipient;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{AssetState, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    SEED_QUEUED_ACTION_ACCOUNT, SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteTreasuryPayment<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Box<Account<'info, VoteState>>,
+
+    #[account(
+        has_one = vote_state,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Box<Account<'info, QueuedAction>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Recipient is validated against the proposal action
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = executor,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_execute_treasury_payment(ctx: Context<ExecuteTreasuryPayment>) -> Result<()> {
+    let ProposalAction::TreasuryPayment { recipient, amount } = ctx
+        .accounts
+        .vote_state
+        .execute_action(&ctx.accounts.queued_action)?
+    else {
+        return Err(ErrorCode::InvalidProposalAction.into());
+    };
+
+    require_keys_eq!(
+        recipient,
+        ctx.accounts.recipient.key(),
+        ErrorCode::InvalidRecipient
+    );
+    require!(
+        ctx.accounts.treasury_usdc_account.amount >= amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.committed_usdc = asset_state.committed_usdc.saturating_sub(amount);
+
+    let asset_key = ctx.accounts.asset.key();
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+    let signer_seeds = &[&treasury_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury_usdc_account.to_account_info(),
+        to: ctx.accounts.recipient_usdc_account.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    msg!("Treasury paid {} USDC to {}", amount, recipient);
+
+    Ok(())
+}
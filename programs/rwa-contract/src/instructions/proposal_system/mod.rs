@@ -0,0 +1,23 @@
+pub mod execute_treasury_auction;
+pub use execute_treasury_auction::*;
+
+pub mod execute_treasury_payment;
+pub use execute_treasury_payment::*;
+
+pub mod queue_proposal;
+pub use queue_proposal::*;
+
+pub mod cancel_queued_action;
+pub use cancel_queued_action::*;
+
+pub mod execute_share_split;
+pub use execute_share_split::*;
+
+pub mod execute_config_update;
+pub use execute_config_update::*;
+
+pub mod execute_treasury_config_update;
+pub use execute_treasury_config_update::*;
+
+pub mod close_queued_action;
+pub use close_queued_action::*;
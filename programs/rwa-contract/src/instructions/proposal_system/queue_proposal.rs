@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{
+    AssetConfig, AssetState, ProposalAction, ProposalStatus, QueuedAction, VoteState,
+};
+use crate::{
+    MIN_CONFIG_UPDATE_DELAY, SEED_ASSET_CONFIG_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT,
+    SEED_STATE_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct QueueProposal<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state and asset_config has_one constraints
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QueuedAction::INIT_SPACE,
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, vote_state.key().as_ref()],
+        bump
+    )]
+    pub queued_action: Account<'info, QueuedAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+    let vote_state = &mut ctx.accounts.vote_state;
+    require!(
+        vote_state.status == ProposalStatus::Passed,
+        ErrorCode::ProposalNotPassed
+    );
+
+    // Cancellations run right away through cancel_queued_action
+    match vote_state.action {
+        None | Some(ProposalAction::CancelQueuedAction { .. }) => {
+            return Err(ErrorCode::InvalidProposalAction.into());
+        }
+        Some(_) => {}
+    }
+
+    // Changes to the governance rules always leave holders time to exit, even when the asset's
+    // own timelock is shorter
+    let delay = match vote_state.action {
+        Some(ProposalAction::UpdateConfig { .. }) => ctx
+            .accounts
+            .asset_config
+            .timelock_delay
+            .max(MIN_CONFIG_UPDATE_DELAY),
+        _ => ctx.accounts.asset_config.timelock_delay,
+    };
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp <= vote_state.queue_deadline(),
+        ErrorCode::ProposalExpired
+    );
+    let eta = clock
+        .unix_timestamp
+        .checked_add(delay)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // A queued payment is owed, so the waterfall and the manager's allowance can't spend it
+    if let Some(ProposalAction::TreasuryPayment { amount, .. }) = vote_state.action {
+        let asset_state = &mut ctx.accounts.asset_state;
+        asset_state.committed_usdc = asset_state
+            .committed_usdc
+            .checked_add(amount)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+
+    let queued_action = &mut ctx.accounts.queued_action;
+    queued_action.vote_state = vote_state.key();
+    queued_action.asset = vote_state.asset;
+    queued_action.queued_at = clock.unix_timestamp;
+    queued_action.eta = eta;
+    queued_action.payer = ctx.accounts.payer.key();
+    queued_action.bump = ctx.bumps.queued_action;
+
+    vote_state.status = ProposalStatus::Queued;
+
+    msg!(
+        "Vote round {} queued, executable at {}",
+        vote_state.vote_round,
+        eta
+    );
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    burn, freeze_account, mint_to, thaw_account, Burn, FreezeAccount, Mint, MintTo, ThawAccount,
+    TokenAccount, TokenInterface,
+};
+
+use crate::error::ErrorCode;
+use crate::state::ShareSplit;
+use crate::{SEED_FREEZE_AUTHORITY_ACCOUNT, SEED_SHARE_SPLIT_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ClaimShareSplit<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = old_mint,
+        has_one = new_mint,
+        seeds = [SEED_SHARE_SPLIT_ACCOUNT, old_mint.key().as_ref()],
+        bump = share_split.bump,
+    )]
+    pub share_split: Account<'info, ShareSplit>,
+
+    #[account(mut)]
+    pub old_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub new_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = old_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+    )]
+    pub holder_old_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = new_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+    )]
+    pub holder_new_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: PDA set as the freeze authority of both share mints, carries holds over
+    #[account(
+        seeds = [SEED_FREEZE_AUTHORITY_ACCOUNT, share_split.asset.as_ref()],
+        bump
+    )]
+    pub freeze_authority: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Burns the holder's old shares and mints the split amount of new shares. A regulatory hold
+/// on the old shares moves to the new ones.
+pub fn handle_claim_share_split(ctx: Context<ClaimShareSplit>) -> Result<()> {
+    let old_shares = ctx.accounts.holder_old_account.amount;
+    require!(old_shares > 0, ErrorCode::NoShares);
+
+    let asset_key = ctx.accounts.share_split.asset;
+    let freeze_seeds = &[
+        SEED_FREEZE_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.freeze_authority],
+    ];
+    let freeze_signer_seeds = &[&freeze_seeds[..]];
+
+    let frozen = ctx.accounts.holder_old_account.is_frozen();
+    if frozen {
+        thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.holder_old_account.to_account_info(),
+                mint: ctx.accounts.old_mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            },
+            freeze_signer_seeds,
+        ))?;
+    }
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.old_mint.to_account_info(),
+                from: ctx.accounts.holder_old_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        old_shares,
+    )?;
+
+    let new_shares = mint_split_shares(
+        &mut ctx.accounts.share_split,
+        &ctx.accounts.new_mint.to_account_info(),
+        &ctx.accounts.holder_new_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        old_shares,
+    )?;
+
+    if frozen {
+        freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.holder_new_account.to_account_info(),
+                mint: ctx.accounts.new_mint.to_account_info(),
+                authority: ctx.accounts.freeze_authority.to_account_info(),
+            },
+            freeze_signer_seeds,
+        ))?;
+    }
+
+    msg!(
+        "Holder {} migrated {} old shares into {} new shares",
+        ctx.accounts.holder.key(),
+        old_shares,
+        new_shares
+    );
+
+    Ok(())
+}
+
+/// Mints the new shares for `old_shares` burned old shares into `to`
+pub fn mint_split_shares<'info>(
+    share_split: &mut Account<'info, ShareSplit>,
+    new_mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    old_shares: u64,
+) -> Result<u64> {
+    let new_shares = share_split.record_claim(old_shares)?;
+
+    let split_seeds = &[
+        SEED_SHARE_SPLIT_ACCOUNT,
+        share_split.old_mint.as_ref(),
+        &[share_split.bump],
+    ];
+
+    // Claims of fewer old shares than one new share mint nothing and leave dust
+    if new_shares > 0 {
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: new_mint.clone(),
+                    to: to.clone(),
+                    authority: share_split.to_account_info(),
+                },
+                &[&split_seeds[..]],
+            ),
+            new_shares,
+        )?;
+    }
+
+    Ok(new_shares)
+}
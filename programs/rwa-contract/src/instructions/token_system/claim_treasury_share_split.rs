@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::token_system::mint_split_shares;
+use crate::state::ShareSplit;
+use crate::{SEED_SHARE_SPLIT_ACCOUNT, SEED_TREASURY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct ClaimTreasuryShareSplit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = old_mint,
+        has_one = new_mint,
+        seeds = [SEED_SHARE_SPLIT_ACCOUNT, old_mint.key().as_ref()],
+        bump = share_split.bump,
+    )]
+    pub share_split: Box<Account<'info, ShareSplit>>,
+
+    #[account(mut)]
+    pub old_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub new_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, share_split.asset.as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = old_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_old_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = new_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_new_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Migrates the shares held by the asset treasury to the new mint. Anyone can call it, so
+/// treasury shares never keep a split from finishing.
+pub fn handle_claim_treasury_share_split(ctx: Context<ClaimTreasuryShareSplit>) -> Result<()> {
+    let old_shares = ctx.accounts.treasury_old_account.amount;
+    require!(old_shares > 0, ErrorCode::NoShares);
+
+    let asset_key = ctx.accounts.share_split.asset;
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.old_mint.to_account_info(),
+                from: ctx.accounts.treasury_old_account.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            &[&treasury_seeds[..]],
+        ),
+        old_shares,
+    )?;
+
+    let new_shares = mint_split_shares(
+        &mut ctx.accounts.share_split,
+        &ctx.accounts.new_mint.to_account_info(),
+        &ctx.accounts.treasury_new_account.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        old_shares,
+    )?;
+
+    msg!(
+        "Treasury migrated {} old shares into {} new shares",
+        old_shares,
+        new_shares
+    );
+
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{
+        spl_token_2022::{
+            extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+            onchain::invoke_transfer_checked,
+            state::{Account as SplTokenAccount, Mint as SplMint},
+        },
+        Token2022,
+    },
+    token_2022_extensions::{immutable_owner_initialize, ImmutableOwnerInitialize},
+    token_interface::{initialize_account3, InitializeAccount3, Mint, TokenAccount},
+};
+use compliance_hook::cpi::accounts::InitializeExtraAccountMetaList;
+use compliance_hook::program::ComplianceHook;
+
+use crate::instructions::token_system::{
+    assign_freeze_authority, distribute_initial_shares, initialize_share_metadata, ShareAllocation,
+    ShareMetadataArgs,
+};
+use crate::{SEED_COMPLIANCE_AUTHORITY_ACCOUNT, SEED_VAULT_AUTHORITY_ACCOUNT};
+
+#[derive(Accounts)]
+#[instruction(decimals: u8, supply: u64, allocations: Vec<ShareAllocation>, asset: Pubkey)]
+pub struct CreateCompliantFungibleToken<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Update authority of the share metadata, initialized with the asset's property NFT
+    #[account(
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.as_ref()],
+        bump
+    )]
+    pub authority_pda: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = decimals,
+        mint::authority = payer,
+        mint::freeze_authority = payer,
+        mint::token_program = token_program,
+        extensions::transfer_hook::program_id = compliance_hook_program,
+        extensions::metadata_pointer::authority = authority_pda,
+        extensions::metadata_pointer::metadata_address = mint,
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    /// CHECK: created and validated by the compliance hook program
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: created and validated by the compliance hook program
+    pub compliance_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub compliance_hook_program: Program<'info, ComplianceHook>,
+}
+
+/// Same as `create_fungible_token`, but on a Token-2022 mint whose transfers are checked
+/// against the investor registry of the compliance hook program. The shares always belong to
+/// the (future) `asset`: its freeze PDA becomes the freeze authority and its compliance
+/// authority PDA manages the registry on behalf of the asset's compliance officer. The mint
+/// carries the same on-mint metadata as in `tokenize_property`.
+///
+/// The transfer hook gets no authority, so the hook program can't be swapped out later.
+pub fn handle_create_compliant_fungible_token<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateCompliantFungibleToken<'info>>,
+    decimals: u8,
+    supply: u64,
+    allocations: Vec<ShareAllocation>,
+    asset: Pubkey,
+    metadata: ShareMetadataArgs,
+) -> Result<()> {
+    // Metadata needs the mint authority, which is revoked once the shares are minted
+    initialize_share_metadata(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &asset,
+        ctx.bumps.authority_pda,
+        metadata,
+    )?;
+
+    assign_freeze_authority(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        Some(asset),
+    )?;
+
+    // The hook checks the payer still holds the mint authority, so this runs before it's revoked
+    compliance_hook::cpi::initialize_extra_account_meta_list(
+        CpiContext::new(
+            ctx.accounts.compliance_hook_program.to_account_info(),
+            InitializeExtraAccountMetaList {
+                payer: ctx.accounts.payer.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                extra_account_meta_list: ctx.accounts.extra_account_meta_list.to_account_info(),
+                compliance_config: ctx.accounts.compliance_config.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+        ),
+        compliance_authority_address(&asset),
+    )?;
+
+    // Minting does not go through the transfer hook, so initial holders need no registry entry
+    distribute_initial_shares(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        ctx.remaining_accounts,
+        decimals,
+        supply,
+        &allocations,
+    )
+}
+
+/// Address managing the investor registry of the shares of `asset`
+pub fn compliance_authority_address(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[SEED_COMPLIANCE_AUTHORITY_ACCOUNT, asset.as_ref()],
+        &crate::ID,
+    )
+    .0
+}
+
+/// Moves shares with `transfer_checked`, resolving the extra accounts of the mint's transfer
+/// hook from `hook_accounts`: the hook program, its extra account meta list and the accounts
+/// it lists. Mints without a transfer hook need no `hook_accounts`.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_shares<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    invoke_transfer_checked(
+        token_program.key,
+        from.clone(),
+        mint.clone(),
+        to.clone(),
+        authority.clone(),
+        hook_accounts,
+        amount,
+        decimals,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Creates a share token account at the program PDA `vault`, owned by `authority`. Anchor's
+/// `token::` init leaves the owner of the account changeable, while the compliance hook only
+/// delivers shares to accounts with the `ImmutableOwner` extension, so the account is created
+/// by hand. Lamports sent to the address beforehand don't block the creation.
+pub fn create_share_vault<'info>(
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    vault_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let is_token_2022 = *mint.owner == Token2022::id();
+    let space = if is_token_2022 {
+        let mint_data = mint.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<SplMint>::unpack(&mint_data)?;
+        let mut extensions =
+            ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+        extensions.push(ExtensionType::ImmutableOwner);
+        ExtensionType::try_calculate_account_len::<SplTokenAccount>(&extensions)?
+    } else {
+        anchor_spl::token::TokenAccount::LEN
+    };
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = vault.lamports();
+
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer.clone(),
+            to: vault.clone(),
+        };
+        create_account(
+            CpiContext::new_with_signer(system_program.clone(), cpi_accounts, vault_seeds),
+            rent,
+            space as u64,
+            token_program.key,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(current_lamports);
+        if top_up > 0 {
+            let cpi_accounts = Transfer {
+                from: payer.clone(),
+                to: vault.clone(),
+            };
+            transfer(
+                CpiContext::new(system_program.clone(), cpi_accounts),
+                top_up,
+            )?;
+        }
+
+        let allocate_accounts = Allocate {
+            account_to_allocate: vault.clone(),
+        };
+        allocate(
+            CpiContext::new_with_signer(system_program.clone(), allocate_accounts, vault_seeds),
+            space as u64,
+        )?;
+
+        let assign_accounts = Assign {
+            account_to_assign: vault.clone(),
+        };
+        assign(
+            CpiContext::new_with_signer(system_program.clone(), assign_accounts, vault_seeds),
+            token_program.key,
+        )?;
+    }
+
+    // Classic SPL Token accounts have no extensions, and such mints carry no transfer hook
+    if is_token_2022 {
+        immutable_owner_initialize(CpiContext::new(
+            token_program.clone(),
+            ImmutableOwnerInitialize {
+                token_program_id: token_program.clone(),
+                token_account: vault.clone(),
+            },
+        ))?;
+    }
+
+    initialize_account3(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount3 {
+            account: vault.clone(),
+            mint: mint.clone(),
+            authority: authority.clone(),
+        },
+    ))
+}
@@ -1,10 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_2022::spl_token_2022::instruction::AuthorityType, 
+    associated_token::{create_idempotent, AssociatedToken, Create},
+    token_2022::spl_token_2022::instruction::AuthorityType,
     token_interface::{mint_to, set_authority, Mint, MintTo, SetAuthority, TokenAccount, TokenInterface}
 };
 
+use crate::error::ErrorCode;
+use crate::SEED_FREEZE_AUTHORITY_ACCOUNT;
+
+/// Whole shares minted to `recipient` when the share mint is created
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ShareAllocation {
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
 #[derive(Accounts)]
 #[instruction(decimals: u8)]
 pub struct CreateFungibleToken<'info> {
@@ -16,6 +26,7 @@ pub struct CreateFungibleToken<'info> {
         payer = payer,
         mint::decimals = decimals,
         mint::authority = payer,
+        mint::freeze_authority = payer,
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
@@ -33,24 +44,154 @@ pub struct CreateFungibleToken<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
-pub fn create_fungible_token_and_revoke_authority(ctx: Context<CreateFungibleToken>, decimals: u8, supply: u8) -> Result<()> {
-    let cpi_accounts = MintTo {
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.payer.to_account_info(),
-    };
+/// Mints `supply` whole shares and revokes the mint authority.
+///
+/// Each allocation needs a `[recipient, recipient_token_account]` pair in `remaining_accounts`,
+/// in the same order. Shares not allocated go to the payer's token account.
+///
+/// With `freeze_asset`, the freeze authority goes to the freeze PDA of that (future) asset so
+/// holders can be frozen through `freeze_holder`, otherwise it is revoked.
+///
+/// The mint gets no metadata, since it may live on the classic token program. Properties are
+/// tokenized with `tokenize_property`, or `create_compliant_fungible_token` for restricted shares;
+/// this instruction remains for classic SPL share mints bound through `create_non_fungible_token`.
+pub fn create_fungible_token_and_revoke_authority<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateFungibleToken<'info>>,
+    decimals: u8,
+    supply: u64,
+    allocations: Vec<ShareAllocation>,
+    freeze_asset: Option<Pubkey>,
+) -> Result<()> {
+    assign_freeze_authority(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        freeze_asset,
+    )?;
+
+    distribute_initial_shares(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        ctx.remaining_accounts,
+        decimals,
+        supply,
+        &allocations,
+    )
+}
+
+/// Converts whole shares into base units of a mint with `decimals`
+pub fn shares_to_base_units(shares: u64, decimals: u8) -> Result<u64> {
+    10u64
+        .checked_pow(decimals as u32)
+        .and_then(|unit| shares.checked_mul(unit))
+        .ok_or(ErrorCode::Overflow.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn distribute_initial_shares<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer_token_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    decimals: u8,
+    supply: u64,
+    allocations: &[ShareAllocation],
+) -> Result<()> {
+    require!(
+        remaining_accounts.len() == allocations.len() * 2,
+        ErrorCode::InvalidAllocation
+    );
+    // The whole supply has to fit in a u64 amount of base units
+    shares_to_base_units(supply, decimals)?;
+
+    let mut unallocated = supply;
+    for (allocation, accounts) in allocations.iter().zip(remaining_accounts.chunks(2)) {
+        let [recipient_info, recipient_token_account_info] = accounts else {
+            return Err(ErrorCode::InvalidAllocation.into());
+        };
+        require!(allocation.amount > 0, ErrorCode::InvalidAllocation);
+        require_keys_eq!(recipient_info.key(), allocation.recipient, ErrorCode::InvalidAllocation);
+        unallocated = unallocated
+            .checked_sub(allocation.amount)
+            .ok_or(ErrorCode::InvalidAllocation)?;
+
+        // The associated token program rejects any account that is not the recipient's ATA
+        create_idempotent(CpiContext::new(
+            associated_token_program.clone(),
+            Create {
+                payer: payer.clone(),
+                associated_token: recipient_token_account_info.clone(),
+                authority: recipient_info.clone(),
+                mint: mint.clone(),
+                system_program: system_program.clone(),
+                token_program: token_program.clone(),
+            },
+        ))?;
+
+        mint_shares(payer, mint, recipient_token_account_info, token_program, allocation.amount, decimals)?;
+    }
+
+    if unallocated > 0 {
+        mint_shares(payer, mint, payer_token_account, token_program, unallocated, decimals)?;
+    }
 
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    mint_to(cpi_ctx, supply as u64 * 10u64.pow(decimals as u32))?;
-    
     let cpi_accounts_for_revoke = SetAuthority{
-        account_or_mint: ctx.accounts.mint.to_account_info(),
-        current_authority: ctx.accounts.payer.to_account_info(),
+        account_or_mint: mint.clone(),
+        current_authority: payer.clone(),
     };
 
-    let cpi_ctx_revoke = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts_for_revoke);
+    let cpi_ctx_revoke = CpiContext::new(token_program.clone(), cpi_accounts_for_revoke);
     set_authority(cpi_ctx_revoke,AuthorityType::MintTokens, None)?;
 
     Ok(())
 }
+
+/// Address allowed to freeze holders of the shares of `asset`
+pub fn freeze_authority_address(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SEED_FREEZE_AUTHORITY_ACCOUNT, asset.as_ref()], &crate::ID).0
+}
+
+/// Hands the payer's freeze authority to the freeze PDA of `freeze_asset`, or revokes it
+pub fn assign_freeze_authority<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    freeze_asset: Option<Pubkey>,
+) -> Result<()> {
+    let cpi_accounts = SetAuthority {
+        account_or_mint: mint.clone(),
+        current_authority: payer.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+    set_authority(
+        cpi_ctx,
+        AuthorityType::FreezeAccount,
+        freeze_asset.as_ref().map(freeze_authority_address),
+    )
+}
+
+fn mint_shares<'info>(
+    authority: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    shares: u64,
+    decimals: u8,
+) -> Result<()> {
+    let cpi_accounts = MintTo {
+        mint: mint.clone(),
+        to: to.clone(),
+        authority: authority.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(token_program.clone(), cpi_accounts);
+    mint_to(cpi_ctx, shares_to_base_units(shares, decimals)?)
+}
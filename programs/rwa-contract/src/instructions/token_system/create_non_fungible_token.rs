@@ -1,15 +1,36 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use mpl_core::instructions::CreateV2CpiBuilder;
+use mpl_core::types::{
+    Attribute, Attributes, PermanentBurnDelegate, Plugin, PluginAuthority, PluginAuthorityPair,
+    UpdateDelegate,
+};
 use mpl_core::ID as MPL_CORE_ID;
 
-use crate::state::AssetState;
-use crate::{SEED_STATE_ACCOUNT, SEED_VAULT_AUTHORITY_ACCOUNT, SEED_VAULT_OWNER_ACCOUNT};
+use crate::error::ErrorCode;
+use crate::instructions::token_system::freeze_authority_address;
+use crate::state::{AssetConfig, AssetState, AssetStatus, PortfolioState};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_PORTFOLIO_ACCOUNT, SEED_STATE_ACCOUNT,
+    SEED_VAULT_AUTHORITY_ACCOUNT, SEED_VAULT_OWNER_ACCOUNT,
+};
+
+/// On-chain property fact, e.g. `parcel_id`, `square_meters`, `jurisdiction` or `appraisal_value`
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct PropertyAttribute {
+    pub key: String,
+    pub value: String,
+}
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateAssetArgs {
     pub name: String,
     pub uri: String,
+    pub attributes: Vec<PropertyAttribute>,
+    /// Seconds passed proposals wait before executing, 2 days when `None`
+    pub timelock_delay: Option<i64>,
+    /// Seconds other holders have to join a large auction, 1 day when `None`
+    pub tag_along_window: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -51,6 +72,26 @@ pub struct CreateNonFungibleToken<'info> {
     )]
     pub asset_state: Account<'info, AssetState>,
 
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetConfig::INIT_SPACE,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    /// CHECK: Portfolio collection the property joins, validated against portfolio
+    #[account(mut)]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [SEED_PORTFOLIO_ACCOUNT, portfolio.collection.as_ref()],
+        bump = portfolio.bump,
+        constraint = portfolio.manager == payer.key() @ ErrorCode::UnauthorizedPortfolioManager,
+    )]
+    pub portfolio: Option<Account<'info, PortfolioState>>,
+
     pub system_program: Program<'info, System>,
 
     #[account(address = MPL_CORE_ID)]
@@ -58,15 +99,69 @@ pub struct CreateNonFungibleToken<'info> {
     pub mpl_core_program: UncheckedAccount<'info>,
 }
 
-pub fn handle_create_non_fungible_token(
-    ctx: Context<CreateNonFungibleToken>,
+/// Shares bound to an asset must be fixed: minted, with no authority left to inflate them and
+/// no freeze authority other than the asset's freeze PDA
+pub fn validate_share_mint(mint: &Mint, asset: &Pubkey) -> Result<()> {
+    let freeze_authority: Option<Pubkey> = mint.freeze_authority.into();
+    require!(
+        mint.supply > 0
+            && mint.mint_authority.is_none()
+            && freeze_authority.is_none_or(|authority| authority == freeze_authority_address(asset)),
+        ErrorCode::InvalidShareMint
+    );
+    Ok(())
+}
+
+/// Portfolio collection a new property NFT is added to, signed by the portfolio PDA
+pub struct PortfolioSigner<'a, 'info> {
+    pub collection: &'a AccountInfo<'info>,
+    pub portfolio: &'a AccountInfo<'info>,
+    pub bump: u8,
+}
+
+/// The collection and portfolio accounts are optional but only make sense together
+pub fn portfolio_signer<'a, 'info>(
+    collection: &'a Option<UncheckedAccount<'info>>,
+    portfolio: &'a Option<Account<'info, PortfolioState>>,
+) -> Result<Option<PortfolioSigner<'a, 'info>>> {
+    match (collection, portfolio) {
+        (Some(collection), Some(portfolio)) => {
+            require_keys_eq!(
+                collection.key(),
+                portfolio.collection,
+                ErrorCode::InvalidPortfolio
+            );
+            Ok(Some(PortfolioSigner {
+                collection: collection.as_ref(),
+                portfolio: portfolio.as_ref(),
+                bump: portfolio.bump,
+            }))
+        }
+        (None, None) => Ok(None),
+        _ => Err(ErrorCode::InvalidPortfolio.into()),
+    }
+}
+
+/// Creates the mpl-core asset held by the `owner` PDA with `authority_pda` as update authority.
+/// The attributes go into an Attributes plugin managed by the update authority, and
+/// `authority_pda` is the permanent burn delegate so a retired property can be burned.
+///
+/// Assets in a portfolio take the collection's update authority, so `authority_pda` is added as
+/// their update delegate and keeps managing the property.
+#[allow(clippy::too_many_arguments)]
+pub fn create_property_asset<'info>(
+    mpl_core_program: &AccountInfo<'info>,
+    asset: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
     args: CreateAssetArgs,
+    owner_bump: u8,
+    authority_bump: u8,
+    portfolio: Option<PortfolioSigner<'_, 'info>>,
 ) -> Result<()> {
-    let cpi_program = ctx.accounts.mpl_core_program.to_account_info();
-
-    let asset_key = ctx.accounts.asset.key();
-    let owner_bump = ctx.bumps.owner;
-    let authority_bump = ctx.bumps.authority_pda;
+    let asset_key = asset.key();
 
     let owner_seeds = &[
         SEED_VAULT_OWNER_ACCOUNT,
@@ -79,21 +174,118 @@ pub fn handle_create_non_fungible_token(
         &[authority_bump],
     ];
 
-    CreateV2CpiBuilder::new(&cpi_program)
-        .asset(&ctx.accounts.asset.to_account_info())
-        .payer(&ctx.accounts.payer.to_account_info())
-        .owner(Some(&ctx.accounts.owner.to_account_info()))
-        .authority(Some(&ctx.accounts.authority_pda.to_account_info()))
-        .system_program(&ctx.accounts.system_program.to_account_info())
+    let attribute_list = args
+        .attributes
+        .into_iter()
+        .map(|attribute| Attribute {
+            key: attribute.key,
+            value: attribute.value,
+        })
+        .collect();
+
+    let mut plugins = vec![
+        PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: Some(PluginAuthority::UpdateAuthority),
+        },
+        PluginAuthorityPair {
+            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+            authority: Some(PluginAuthority::Address {
+                address: authority_pda.key(),
+            }),
+        },
+    ];
+
+    let mut create = CreateV2CpiBuilder::new(mpl_core_program);
+    create
+        .asset(asset)
+        .payer(payer)
+        .owner(Some(owner))
+        .system_program(system_program)
         .uri(args.uri)
-        .name(args.name)
-        .invoke_signed(&[owner_seeds, authority_seeds])?;
+        .name(args.name);
+
+    match portfolio {
+        Some(portfolio) => {
+            plugins.push(PluginAuthorityPair {
+                plugin: Plugin::UpdateDelegate(UpdateDelegate {
+                    additional_delegates: vec![],
+                }),
+                authority: Some(PluginAuthority::Address {
+                    address: authority_pda.key(),
+                }),
+            });
+
+            let collection_key = portfolio.collection.key();
+            let portfolio_seeds = &[
+                SEED_PORTFOLIO_ACCOUNT,
+                collection_key.as_ref(),
+                &[portfolio.bump],
+            ];
+
+            create
+                .collection(Some(portfolio.collection))
+                .authority(Some(portfolio.portfolio))
+                .plugins(plugins)
+                .invoke_signed(&[owner_seeds, portfolio_seeds])?;
+        }
+        None => {
+            create
+                .authority(Some(authority_pda))
+                .plugins(plugins)
+                .invoke_signed(&[owner_seeds, authority_seeds])?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn handle_create_non_fungible_token(
+    ctx: Context<CreateNonFungibleToken>,
+    args: CreateAssetArgs,
+) -> Result<()> {
+    validate_share_mint(&ctx.accounts.ft_mint, &ctx.accounts.asset.key())?;
+    let timelock_delay = args.timelock_delay;
+    let tag_along_window = args.tag_along_window;
+
+    create_property_asset(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        &ctx.accounts.asset.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        args,
+        ctx.bumps.owner,
+        ctx.bumps.authority_pda,
+        portfolio_signer(&ctx.accounts.collection, &ctx.accounts.portfolio)?,
+    )?;
 
     let asset_state = &mut ctx.accounts.asset_state;
     asset_state.asset = ctx.accounts.asset.key();
     asset_state.ft_mint = ctx.accounts.ft_mint.key();
     asset_state.total_shares = ctx.accounts.ft_mint.supply;
+    asset_state.collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|collection| collection.key());
+    asset_state.status = AssetStatus::Active;
+    asset_state.open_auctions = 0;
+    asset_state.open_vote_rounds = 0;
+    asset_state.committed_usdc = 0;
+    asset_state.treasury_income = 0;
+    asset_state.last_waterfall_time = 0;
+    asset_state.share_split_in_progress = false;
     asset_state.bump = ctx.bumps.asset_state;
 
+    ctx.accounts.asset_config.initialize(
+        ctx.accounts.asset.key(),
+        ctx.accounts.payer.key(),
+        timelock_delay,
+        tag_along_window,
+        ctx.bumps.asset_config,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateCollectionV2CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::state::PortfolioState;
+use crate::SEED_PORTFOLIO_ACCOUNT;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreatePortfolioArgs {
+    pub name: String,
+    pub uri: String,
+}
+
+#[derive(Accounts)]
+pub struct CreatePortfolio<'info> {
+    #[account(mut)]
+    pub manager: Signer<'info>,
+
+    #[account(mut)]
+    pub collection: Signer<'info>,
+
+    #[account(
+        init,
+        payer = manager,
+        space = 8 + PortfolioState::INIT_SPACE,
+        seeds = [SEED_PORTFOLIO_ACCOUNT, collection.key().as_ref()],
+        bump
+    )]
+    pub portfolio: Account<'info, PortfolioState>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Creates an mpl-core collection owned by the portfolio PDA. Properties join it at tokenization.
+pub fn handle_create_portfolio(
+    ctx: Context<CreatePortfolio>,
+    args: CreatePortfolioArgs,
+) -> Result<()> {
+    CreateCollectionV2CpiBuilder::new(&ctx.accounts.mpl_core_program.to_account_info())
+        .collection(&ctx.accounts.collection.to_account_info())
+        .update_authority(Some(&ctx.accounts.portfolio.to_account_info()))
+        .payer(&ctx.accounts.manager.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(args.name)
+        .uri(args.uri)
+        .invoke()?;
+
+    let portfolio = &mut ctx.accounts.portfolio;
+    portfolio.collection = ctx.accounts.collection.key();
+    portfolio.manager = ctx.accounts.manager.key();
+    portfolio.bump = ctx.bumps.portfolio;
+
+    msg!("Portfolio {} created", portfolio.collection);
+
+    Ok(())
+}
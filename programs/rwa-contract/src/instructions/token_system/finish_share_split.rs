@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{
+    mint_to, set_authority, Mint, MintTo, SetAuthority, TokenAccount, TokenInterface,
+};
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, ShareSplit};
+use crate::{SEED_SHARE_SPLIT_ACCOUNT, SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct FinishShareSplit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = new_mint,
+        seeds = [SEED_SHARE_SPLIT_ACCOUNT, share_split.old_mint.as_ref()],
+        bump = share_split.bump,
+    )]
+    pub share_split: Box<Account<'info, ShareSplit>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STATE_ACCOUNT, share_split.asset.as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        mut,
+        constraint = new_mint.mint_authority == Some(share_split.key()).into() @ ErrorCode::InvalidMint,
+    )]
+    pub new_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, share_split.asset.as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = new_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_new_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Closes a split once every old share migrated. Each claim rounds down, so the new shares
+/// the old supply converts to as a whole but no holder received go to the treasury, then the
+/// new supply is fixed like any other share mint and the asset opens up for auctions, vote
+/// rounds and distributions again. Anyone can call it.
+pub fn handle_finish_share_split(ctx: Context<FinishShareSplit>) -> Result<()> {
+    let share_split = &ctx.accounts.share_split;
+    require!(
+        share_split.remaining_old_shares == 0,
+        ErrorCode::ShareSplitInProgress
+    );
+
+    let dust = share_split
+        .expected_new_shares
+        .checked_sub(share_split.minted_new_shares)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let split_seeds = &[
+        SEED_SHARE_SPLIT_ACCOUNT,
+        share_split.old_mint.as_ref(),
+        &[share_split.bump],
+    ];
+    let signer_seeds = &[&split_seeds[..]];
+
+    if dust > 0 {
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.new_mint.to_account_info(),
+                    to: ctx.accounts.treasury_new_account.to_account_info(),
+                    authority: ctx.accounts.share_split.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            dust,
+        )?;
+    }
+
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                account_or_mint: ctx.accounts.new_mint.to_account_info(),
+                current_authority: ctx.accounts.share_split.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let share_split = &mut ctx.accounts.share_split;
+    share_split.minted_new_shares = share_split.expected_new_shares;
+
+    ctx.accounts.asset_state.share_split_in_progress = false;
+
+    msg!(
+        "Share split into {} finished, {} new shares of dust went to the treasury",
+        ctx.accounts.new_mint.key(),
+        dust
+    );
+
+    Ok(())
+}
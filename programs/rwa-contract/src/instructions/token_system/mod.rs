@@ -3,3 +3,30 @@ pub use create_fungible_token::*;
 
 pub mod create_non_fungible_token;
 pub use create_non_fungible_token::*;
+
+pub mod update_asset_config;
+pub use update_asset_config::*;
+
+pub mod tokenize_property;
+pub use tokenize_property::*;
+
+pub mod create_compliant_fungible_token;
+pub use create_compliant_fungible_token::*;
+
+pub mod update_property_metadata;
+pub use update_property_metadata::*;
+
+pub mod update_property_attribute;
+pub use update_property_attribute::*;
+
+pub mod create_portfolio;
+pub use create_portfolio::*;
+
+pub mod claim_share_split;
+pub use claim_share_split::*;
+
+pub mod claim_treasury_share_split;
+pub use claim_treasury_share_split::*;
+
+pub mod finish_share_split;
+pub use finish_share_split::*;
@@ -0,0 +1,313 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize, token_metadata_update_field, Mint, TokenAccount,
+        TokenMetadataInitialize, TokenMetadataUpdateField,
+    },
+};
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::error::ErrorCode;
+use crate::instructions::token_system::{
+    assign_freeze_authority, create_property_asset, distribute_initial_shares, portfolio_signer,
+    validate_share_mint, CreateAssetArgs, PropertyAttribute, ShareAllocation,
+};
+use crate::state::{AssetConfig, AssetState, AssetStatus, PortfolioState};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_PORTFOLIO_ACCOUNT, SEED_STATE_ACCOUNT,
+    SEED_VAULT_AUTHORITY_ACCOUNT, SEED_VAULT_OWNER_ACCOUNT, SHARE_METADATA_ASSET_FIELD,
+};
+
+/// On-mint metadata of a share mint
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ShareMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct TokenizePropertyArgs {
+    /// Used for both the property NFT and the share mint metadata
+    pub name: String,
+    /// Symbol of the share mint
+    pub symbol: String,
+    pub uri: String,
+    pub attributes: Vec<PropertyAttribute>,
+    pub decimals: u8,
+    /// Number of whole shares minted
+    pub supply: u64,
+    /// Shares not allocated here go to the payer
+    pub allocations: Vec<ShareAllocation>,
+    /// Keep a freeze authority on the shares for regulatory holds through `freeze_holder`
+    pub regulatory_freeze: bool,
+    /// Seconds passed proposals wait before executing, 2 days when `None`
+    pub timelock_delay: Option<i64>,
+    /// Seconds other holders have to join a large auction, 1 day when `None`
+    pub tag_along_window: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: TokenizePropertyArgs)]
+pub struct TokenizeProperty<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut)]
+    pub asset: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 0,
+        seeds = [SEED_VAULT_OWNER_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    /// CHECK:
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 0,
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    /// CHECK:
+    pub authority_pda: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = args.decimals,
+        mint::authority = payer,
+        mint::freeze_authority = payer,
+        mint::token_program = token_program,
+        extensions::metadata_pointer::authority = authority_pda,
+        extensions::metadata_pointer::metadata_address = ft_mint,
+    )]
+    pub ft_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = ft_mint,
+        associated_token::authority = payer,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetState::INIT_SPACE,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetConfig::INIT_SPACE,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    /// CHECK: Portfolio collection the property joins, validated against portfolio
+    #[account(mut)]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [SEED_PORTFOLIO_ACCOUNT, portfolio.collection.as_ref()],
+        bump = portfolio.bump,
+        constraint = portfolio.manager == payer.key() @ ErrorCode::UnauthorizedPortfolioManager,
+    )]
+    pub portfolio: Option<Account<'info, PortfolioState>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Allocation recipients are passed through `remaining_accounts` as in `create_fungible_token`
+pub fn handle_tokenize_property<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TokenizeProperty<'info>>,
+    args: TokenizePropertyArgs,
+) -> Result<()> {
+    // Metadata needs the mint authority, which is revoked once the shares are minted
+    initialize_share_metadata(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.asset.key(),
+        ctx.bumps.authority_pda,
+        ShareMetadataArgs {
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+        },
+    )?;
+
+    assign_freeze_authority(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        args.regulatory_freeze.then(|| ctx.accounts.asset.key()),
+    )?;
+
+    distribute_initial_shares(
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.token_account.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.associated_token_program.to_account_info(),
+        ctx.remaining_accounts,
+        args.decimals,
+        args.supply,
+        &args.allocations,
+    )?;
+
+    ctx.accounts.ft_mint.reload()?;
+    validate_share_mint(&ctx.accounts.ft_mint, &ctx.accounts.asset.key())?;
+
+    create_property_asset(
+        &ctx.accounts.mpl_core_program.to_account_info(),
+        &ctx.accounts.asset.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.authority_pda.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CreateAssetArgs {
+            name: args.name,
+            uri: args.uri,
+            attributes: args.attributes,
+            timelock_delay: args.timelock_delay,
+            tag_along_window: args.tag_along_window,
+        },
+        ctx.bumps.owner,
+        ctx.bumps.authority_pda,
+        portfolio_signer(&ctx.accounts.collection, &ctx.accounts.portfolio)?,
+    )?;
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.asset = ctx.accounts.asset.key();
+    asset_state.ft_mint = ctx.accounts.ft_mint.key();
+    asset_state.total_shares = ctx.accounts.ft_mint.supply;
+    asset_state.collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|collection| collection.key());
+    asset_state.status = AssetStatus::Active;
+    asset_state.open_auctions = 0;
+    asset_state.open_vote_rounds = 0;
+    asset_state.committed_usdc = 0;
+    asset_state.treasury_income = 0;
+    asset_state.last_waterfall_time = 0;
+    asset_state.share_split_in_progress = false;
+    asset_state.bump = ctx.bumps.asset_state;
+
+    ctx.accounts.asset_config.initialize(
+        ctx.accounts.asset.key(),
+        ctx.accounts.payer.key(),
+        args.timelock_delay,
+        args.tag_along_window,
+        ctx.bumps.asset_config,
+    )?;
+
+    Ok(())
+}
+
+/// Writes the property name, symbol and uri into the share mint, plus a field holding the
+/// mpl-core asset so wallets can link the shares to their property. `authority_pda` of the asset
+/// is the update authority, like on the property NFT. `payer` must still be the mint authority.
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_share_metadata<'info>(
+    payer: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority_pda: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    asset: &Pubkey,
+    authority_bump: u8,
+    args: ShareMetadataArgs,
+) -> Result<()> {
+    let authority_seeds = &[
+        SEED_VAULT_AUTHORITY_ACCOUNT,
+        asset.as_ref(),
+        &[authority_bump],
+    ];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    let metadata = TokenMetadata {
+        update_authority: Some(authority_pda.key()).try_into()?,
+        mint: mint.key(),
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
+        additional_metadata: vec![(SHARE_METADATA_ASSET_FIELD.to_string(), asset.to_string())],
+    };
+
+    // The token program grows the mint for the metadata but expects the rent to be there already
+    let required_lamports = Rent::get()?.minimum_balance(mint.data_len() + metadata.tlv_size_of()?);
+    let top_up = required_lamports.saturating_sub(mint.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    token_metadata_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: authority_pda.clone(),
+                mint_authority: payer.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+
+    for (key, value) in metadata.additional_metadata {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: mint.clone(),
+                    update_authority: authority_pda.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key),
+            value,
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::AssetConfig;
+use crate::SEED_ASSET_CONFIG_ACCOUNT;
+
+/// Fields left as `None` keep their current value. The voting rules, timelock delay, guardian,
+/// buyout supermajority and tag-along settings can only be changed by an `UpdateConfig`
+/// proposal, the asset manager, its treasury allowance and the waterfall settings by an
+/// `UpdateTreasuryConfig` proposal.
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAssetConfigArgs {
+    pub authority: Option<Pubkey>,
+    pub compliance_officer: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAssetConfig<'info> {
+    pub authority: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_config has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = authority,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+}
+
+pub fn handle_update_asset_config(
+    ctx: Context<UpdateAssetConfig>,
+    args: UpdateAssetConfigArgs,
+) -> Result<()> {
+    let asset_config = &mut ctx.accounts.asset_config;
+
+    if let Some(authority) = args.authority {
+        asset_config.authority = authority;
+    }
+    if let Some(compliance_officer) = args.compliance_officer {
+        asset_config.compliance_officer = compliance_officer;
+    }
+
+    Ok(())
+}
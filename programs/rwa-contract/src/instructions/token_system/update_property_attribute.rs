@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::{AddPluginV1CpiBuilder, UpdatePluginV1CpiBuilder};
+use mpl_core::types::{Attribute, Attributes, Plugin, PluginAuthority, PluginType};
+use mpl_core::{fetch_asset_plugin, ID as MPL_CORE_ID};
+
+use crate::state::AssetConfig;
+use crate::{SEED_ASSET_CONFIG_ACCOUNT, SEED_VAULT_AUTHORITY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct UpdatePropertyAttribute<'info> {
+    #[account(mut)]
+    pub asset_manager: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_config and by mpl-core
+    #[account(mut)]
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = asset_manager,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    /// CHECK: PDA set as the mpl-core update authority, or update delegate in a portfolio
+    #[account(
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Portfolio collection of the property NFT, validated by mpl-core
+    #[account(mut)]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+/// Sets a single attribute of the property, an empty value removes it
+pub fn handle_update_property_attribute(
+    ctx: Context<UpdatePropertyAttribute>,
+    key: String,
+    value: String,
+) -> Result<()> {
+    let asset = ctx.accounts.asset.to_account_info();
+
+    // Assets created before attributes were supported have no plugin yet
+    let existing = fetch_asset_plugin::<Attributes>(&asset, PluginType::Attributes).ok();
+    let mut attribute_list = existing
+        .as_ref()
+        .map(|(_, attributes, _)| attributes.attribute_list.clone())
+        .unwrap_or_default();
+
+    attribute_list.retain(|attribute| attribute.key != key);
+    if !value.is_empty() {
+        attribute_list.push(Attribute {
+            key: key.clone(),
+            value,
+        });
+    }
+    let plugin = Plugin::Attributes(Attributes { attribute_list });
+
+    let asset_key = ctx.accounts.asset.key();
+    let authority_seeds = &[
+        SEED_VAULT_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let payer = ctx.accounts.asset_manager.to_account_info();
+    let authority_pda = ctx.accounts.authority_pda.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|collection| collection.as_ref());
+
+    if existing.is_some() {
+        UpdatePluginV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(collection)
+            .payer(&payer)
+            .authority(Some(&authority_pda))
+            .system_program(&system_program)
+            .plugin(plugin)
+            .invoke_signed(&[authority_seeds])?;
+    } else {
+        AddPluginV1CpiBuilder::new(&mpl_core_program)
+            .asset(&asset)
+            .collection(collection)
+            .payer(&payer)
+            .authority(Some(&authority_pda))
+            .system_program(&system_program)
+            .plugin(plugin)
+            .init_authority(PluginAuthority::UpdateAuthority)
+            .invoke_signed(&[authority_seeds])?;
+    }
+
+    msg!("Attribute {} of property {} updated", key, asset_key);
+
+    Ok(())
+}
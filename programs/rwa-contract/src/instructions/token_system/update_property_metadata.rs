@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::UpdateV1CpiBuilder;
+use mpl_core::ID as MPL_CORE_ID;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, ProposalAction, QueuedAction, VoteState};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_QUEUED_ACTION_ACCOUNT, SEED_VAULT_AUTHORITY_ACCOUNT,
+    SEED_VOTE_STATE_ACCOUNT,
+};
+
+/// Fields left as `None` keep their current value
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePropertyMetadataArgs {
+    pub name: Option<String>,
+    pub uri: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePropertyMetadata<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_config and by mpl-core
+    #[account(mut)]
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
+    /// Queued round carrying the same update, not needed when the asset manager signs
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Option<Account<'info, VoteState>>,
+
+    #[account(
+        seeds = [SEED_QUEUED_ACTION_ACCOUNT, queued_action.vote_state.as_ref()],
+        bump = queued_action.bump,
+    )]
+    pub queued_action: Option<Account<'info, QueuedAction>>,
+
+    /// CHECK: PDA set as the mpl-core update authority, or update delegate in a portfolio
+    #[account(
+        seeds = [SEED_VAULT_AUTHORITY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Portfolio collection of the property NFT, validated by mpl-core
+    #[account(mut)]
+    pub collection: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+pub fn handle_update_property_metadata(
+    ctx: Context<UpdatePropertyMetadata>,
+    args: UpdatePropertyMetadataArgs,
+) -> Result<()> {
+    // Without the asset manager's signature the update must match a queued proposal
+    if ctx.accounts.executor.key() != ctx.accounts.asset_config.asset_manager {
+        let (Some(vote_state), Some(queued_action)) = (
+            ctx.accounts.vote_state.as_mut(),
+            ctx.accounts.queued_action.as_ref(),
+        ) else {
+            return Err(ErrorCode::UnauthorizedMetadataUpdate.into());
+        };
+        require_keys_eq!(
+            queued_action.vote_state,
+            vote_state.key(),
+            ErrorCode::InvalidProposalAction
+        );
+
+        let action = vote_state.execute_action(queued_action)?;
+        require!(
+            action
+                == ProposalAction::UpdatePropertyMetadata {
+                    name: args.name.clone(),
+                    uri: args.uri.clone(),
+                },
+            ErrorCode::InvalidProposalAction
+        );
+    }
+
+    let asset_key = ctx.accounts.asset.key();
+    let authority_seeds = &[
+        SEED_VAULT_AUTHORITY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.authority_pda],
+    ];
+
+    let mpl_core_program = ctx.accounts.mpl_core_program.to_account_info();
+    let asset = ctx.accounts.asset.to_account_info();
+    let executor = ctx.accounts.executor.to_account_info();
+    let authority_pda = ctx.accounts.authority_pda.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let collection = ctx
+        .accounts
+        .collection
+        .as_ref()
+        .map(|collection| collection.as_ref());
+
+    let mut update = UpdateV1CpiBuilder::new(&mpl_core_program);
+    update
+        .asset(&asset)
+        .collection(collection)
+        .payer(&executor)
+        .authority(Some(&authority_pda))
+        .system_program(&system_program);
+    if let Some(name) = args.name {
+        update.new_name(name);
+    }
+    if let Some(uri) = args.uri {
+        update.new_uri(uri);
+    }
+    update.invoke_signed(&[authority_seeds])?;
+
+    msg!("Metadata of property {} updated", asset_key);
+
+    Ok(())
+}
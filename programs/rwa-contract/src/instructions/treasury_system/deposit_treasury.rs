@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::AssetState;
+use crate::{SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct DepositTreasury<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = depositor,
+        token::token_program = token_program,
+    )]
+    pub depositor_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Pays rent, fees or reserves into the asset treasury, counted as income for the next waterfall
+pub fn handle_deposit_treasury(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidTreasuryDeposit);
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.depositor_usdc_account.to_account_info(),
+        to: ctx.accounts.treasury_usdc_account.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.treasury_income = asset_state
+        .treasury_income
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    msg!(
+        "Deposited {} USDC into the treasury of {}",
+        amount,
+        ctx.accounts.asset.key()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+pub mod deposit_treasury;
+pub use deposit_treasury::*;
+
+pub mod spend_treasury_allowance;
+pub use spend_treasury_allowance::*;
+
+pub mod run_waterfall;
+pub use run_waterfall::*;
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState, AssetStatus, Distribution};
+use crate::{
+    BPS_DENOMINATOR, DISTRIBUTION_CLAIM_WINDOW, MIN_WATERFALL_INTERVAL, SEED_ASSET_CONFIG_ACCOUNT,
+    SEED_DISTRIBUTION_ACCOUNT, SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT,
+};
+
+#[derive(Accounts)]
+#[instruction(period: u64)]
+pub struct RunWaterfall<'info> {
+    #[account(mut)]
+    pub asset_manager: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        has_one = asset,
+        has_one = asset_manager @ ErrorCode::UnauthorizedDistributionDeposit,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = asset_manager,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = asset_manager,
+        associated_token::token_program = token_program,
+    )]
+    pub manager_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Required when the asset has debt service configured
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::token_program = token_program,
+        constraint = debt_service_usdc_account.owner == asset_config.debt_service_recipient @ ErrorCode::InvalidDebtServiceAccount,
+    )]
+    pub debt_service_usdc_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init,
+        payer = asset_manager,
+        space = 8 + Distribution::INIT_SPACE,
+        seeds = [SEED_DISTRIBUTION_ACCOUNT, asset.key().as_ref(), period.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distribution: Box<Account<'info, Distribution>>,
+
+    #[account(
+        init,
+        payer = asset_manager,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = distribution,
+        associated_token::token_program = token_program,
+    )]
+    pub distribution_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// How the treasury balance is split by one run of the waterfall
+pub struct WaterfallSplit {
+    pub reserve: u64,
+    pub management_fee: u64,
+    pub debt_service: u64,
+    pub holders: u64,
+}
+
+impl WaterfallSplit {
+    /// Fills the reserve first, takes the management fee and then debt service from the
+    /// `income` above it, and leaves the rest, including auction proceeds and swept
+    /// distributions, for the holders
+    pub fn compute(balance: u64, income: u64, asset_config: &AssetConfig) -> WaterfallSplit {
+        let reserve = balance.min(asset_config.reserve_target);
+        let above_reserve = balance - reserve;
+        let income = income.min(above_reserve);
+        let management_fee = (income as u128 * asset_config.management_fee_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        let debt_service = (income - management_fee).min(asset_config.debt_service_amount);
+        WaterfallSplit {
+            reserve,
+            management_fee,
+            debt_service,
+            holders: above_reserve - management_fee - debt_service,
+        }
+    }
+}
+
+fn pay_from_treasury<'info>(
+    accounts: &RunWaterfall<'info>,
+    to: AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = TransferChecked {
+        from: accounts.treasury_usdc_account.to_account_info(),
+        to,
+        authority: accounts.treasury.to_account_info(),
+        mint: accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, amount, accounts.usdc_mint.decimals)
+}
+
+/// Splits the treasury balance not committed to queued payments for a period: the reserve
+/// stays in the treasury, the fee goes to the asset manager, debt service to the lender, and
+/// the rest into a distribution the holders claim with `claim_distribution` against the share
+/// balances in `snapshot_root`. The distribution records the debt service paid for the period.
+/// Runs at most once per `MIN_WATERFALL_INTERVAL`.
+pub fn handle_run_waterfall(
+    ctx: Context<RunWaterfall>,
+    period: u64,
+    snapshot_root: [u8; 32],
+) -> Result<()> {
+    require!(
+        ctx.accounts.asset_state.status == AssetStatus::Active,
+        ErrorCode::AssetNotActive
+    );
+    require!(
+        !ctx.accounts.asset_state.share_split_in_progress,
+        ErrorCode::ShareSplitInProgress
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let last_waterfall_time = ctx.accounts.asset_state.last_waterfall_time;
+    require!(
+        last_waterfall_time == 0
+            || now >= last_waterfall_time.saturating_add(MIN_WATERFALL_INTERVAL),
+        ErrorCode::WaterfallTooEarly
+    );
+
+    // Queued treasury payments stay in the treasury for their proposal to pay out
+    let available = ctx
+        .accounts
+        .treasury_usdc_account
+        .amount
+        .saturating_sub(ctx.accounts.asset_state.committed_usdc);
+    let split = WaterfallSplit::compute(
+        available,
+        ctx.accounts.asset_state.treasury_income,
+        &ctx.accounts.asset_config,
+    );
+
+    let asset_key = ctx.accounts.asset.key();
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+    let signer_seeds = &[&treasury_seeds[..]];
+
+    pay_from_treasury(
+        ctx.accounts,
+        ctx.accounts.manager_usdc_account.to_account_info(),
+        split.management_fee,
+        signer_seeds,
+    )?;
+
+    if split.debt_service > 0 {
+        let debt_service_usdc_account = ctx
+            .accounts
+            .debt_service_usdc_account
+            .as_ref()
+            .ok_or(ErrorCode::InvalidDebtServiceAccount)?;
+        pay_from_treasury(
+            ctx.accounts,
+            debt_service_usdc_account.to_account_info(),
+            split.debt_service,
+            signer_seeds,
+        )?;
+    }
+
+    pay_from_treasury(
+        ctx.accounts,
+        ctx.accounts.distribution_vault.to_account_info(),
+        split.holders,
+        signer_seeds,
+    )?;
+
+    ctx.accounts.distribution.initialize(
+        asset_key,
+        ctx.accounts.asset_state.ft_mint,
+        ctx.accounts.usdc_mint.key(),
+        ctx.accounts.treasury.key(),
+        period,
+        split.holders,
+        ctx.accounts.asset_state.total_shares,
+        snapshot_root,
+        now.checked_add(DISTRIBUTION_CLAIM_WINDOW)
+            .ok_or(ErrorCode::Overflow)?,
+        ctx.bumps.distribution,
+    )?;
+    let distribution = &mut ctx.accounts.distribution;
+    distribution.debt_service = split.debt_service;
+    distribution.debt_service_recipient = ctx.accounts.asset_config.debt_service_recipient;
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.treasury_income = 0;
+    asset_state.last_waterfall_time = now;
+
+    msg!(
+        "Waterfall for period {}: {} USDC reserve, {} management fee, {} debt service, {} to holders",
+        period,
+        split.reserve,
+        split.management_fee,
+        split.debt_service,
+        split.holders
+    );
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::USDC_MINT_DEVNET;
+use crate::error::ErrorCode;
+use crate::state::{AssetConfig, AssetState, TreasuryAllowance};
+use crate::{
+    SEED_ASSET_CONFIG_ACCOUNT, SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT,
+    SEED_TREASURY_ALLOWANCE_ACCOUNT,
+};
+
+#[derive(Accounts)]
+pub struct SpendTreasuryAllowance<'info> {
+    #[account(mut)]
+    pub asset_manager: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_config has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        has_one = asset_manager @ ErrorCode::UnauthorizedTreasurySpend,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Box<Account<'info, AssetConfig>>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Box<Account<'info, AssetState>>,
+
+    #[account(
+        init_if_needed,
+        payer = asset_manager,
+        space = 8 + TreasuryAllowance::INIT_SPACE,
+        seeds = [SEED_TREASURY_ALLOWANCE_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury_allowance: Box<Account<'info, TreasuryAllowance>>,
+
+    #[account(
+        constraint = usdc_mint.key().to_string() == USDC_MINT_DEVNET @ ErrorCode::InvalidBidToken
+    )]
+    pub usdc_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: PDA that owns the asset treasury token accounts
+    #[account(
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Any wallet the manager pays for repairs, taxes or other expenses
+    pub recipient: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = asset_manager,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_usdc_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Lets the asset manager pay routine expenses from the treasury without a vote, up to the
+/// allowance configured for each period
+pub fn handle_spend_treasury_allowance(
+    ctx: Context<SpendTreasuryAllowance>,
+    amount: u64,
+) -> Result<()> {
+    // Queued treasury payments can't be spent by the manager
+    let available = ctx
+        .accounts
+        .treasury_usdc_account
+        .amount
+        .saturating_sub(ctx.accounts.asset_state.committed_usdc);
+    require!(available >= amount, ErrorCode::InsuficientTokenBalance);
+
+    let asset_config = &ctx.accounts.asset_config;
+    let treasury_allowance = &mut ctx.accounts.treasury_allowance;
+    treasury_allowance.asset = ctx.accounts.asset.key();
+    treasury_allowance.bump = ctx.bumps.treasury_allowance;
+    treasury_allowance.spend(
+        amount,
+        asset_config.manager_allowance,
+        asset_config.allowance_period,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let asset_key = ctx.accounts.asset.key();
+    let treasury_seeds = &[
+        SEED_TREASURY_ACCOUNT,
+        asset_key.as_ref(),
+        &[ctx.bumps.treasury],
+    ];
+    let signer_seeds = &[&treasury_seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury_usdc_account.to_account_info(),
+        to: ctx.accounts.recipient_usdc_account.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.usdc_mint.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    transfer_checked(cpi_ctx, amount, ctx.accounts.usdc_mint.decimals)?;
+
+    msg!(
+        "Asset manager paid {} USDC from the treasury to {}",
+        amount,
+        ctx.accounts.recipient.key()
+    );
+
+    Ok(())
+}
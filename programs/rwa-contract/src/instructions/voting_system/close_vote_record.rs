@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{ProposalStatus, VoteRecord, VoteState};
+use crate::SEED_VOTE_RECORD_ACCOUNT;
+
+#[derive(Accounts)]
+pub struct CloseVoteRecord<'info> {
+    /// Wallet that paid for the record, the voter or its delegate
+    #[account(mut)]
+    pub cast_by: Signer<'info>,
+
+    /// CHECK: Either a finalized VoteState or an already closed one, validated in the handler
+    pub vote_state: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = cast_by,
+        has_one = cast_by,
+        has_one = vote_state @ ErrorCode::InvalidVoteRecord,
+        seeds = [SEED_VOTE_RECORD_ACCOUNT, vote_state.key().as_ref(), vote_record.voter.as_ref()],
+        bump = vote_record.bump,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+pub fn handle_close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+    // Rounds can only be closed once finalized and their address is never reused,
+    // so an empty vote_state account means the round is over
+    let vote_state_info = &ctx.accounts.vote_state;
+    if !vote_state_info.data_is_empty() {
+        require_keys_eq!(
+            *vote_state_info.owner,
+            crate::ID,
+            ErrorCode::InvalidVoteRecord
+        );
+        let vote_state = VoteState::try_deserialize(&mut &vote_state_info.try_borrow_data()?[..])?;
+        require!(
+            vote_state.status != ProposalStatus::Active,
+            ErrorCode::VoteRoundNotFinalized
+        );
+    }
+
+    Ok(())
+}
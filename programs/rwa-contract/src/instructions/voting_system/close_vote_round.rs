@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{VoteResult, VoteState};
+use crate::{SEED_VOTE_RESULT_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct CloseVoteRound<'info> {
+    #[account(mut)]
+    pub voting_creator: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = voting_creator,
+        has_one = asset,
+        has_one = voting_creator,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), voting_creator.key().as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    /// Pass to keep a compact copy of the outcome after closing the round
+    #[account(
+        init,
+        payer = voting_creator,
+        space = 8 + VoteResult::INIT_SPACE,
+        seeds = [SEED_VOTE_RESULT_ACCOUNT, vote_state.key().as_ref()],
+        bump
+    )]
+    pub vote_result: Option<Account<'info, VoteResult>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_close_vote_round(ctx: Context<CloseVoteRound>) -> Result<()> {
+    let vote_state = &ctx.accounts.vote_state;
+    require!(
+        vote_state.is_settled(Clock::get()?.unix_timestamp),
+        ErrorCode::ProposalPending
+    );
+
+    if let Some(vote_result) = ctx.accounts.vote_result.as_mut() {
+        vote_result.asset = vote_state.asset;
+        vote_result.voting_creator = vote_state.voting_creator;
+        vote_result.vote_round = vote_state.vote_round;
+        vote_result.content_hash = vote_state.content_hash;
+        vote_result.yes_weight = vote_state.yes_weight;
+        vote_result.no_weight = vote_state.no_weight;
+        vote_result.turnout = vote_state.turnout;
+        vote_result.status = vote_state.status;
+        vote_result.bump = ctx.bumps.vote_result.ok_or(ErrorCode::InvalidVoteRecord)?;
+    }
+
+    Ok(())
+}
@@ -1,9 +1,26 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::error::ErrorCode;
-use crate::state::{AssetState, VoteRoundIndexState, VoteState};
-use crate::{SEED_STATE_ACCOUNT, SEED_VOTE_ROUND_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
+use crate::state::{
+    AssetConfig, AssetState, AssetStatus, ProposalAction, ProposalStatus, VoteRoundIndexState,
+    VoteState, VoterLock, VotingMode,
+};
+use crate::{
+    BPS_DENOMINATOR, MAX_PROPOSAL_URI_LENGTH, SEED_ASSET_CONFIG_ACCOUNT, SEED_STATE_ACCOUNT,
+    SEED_VOTER_LOCK_ACCOUNT, SEED_VOTE_ROUND_ACCOUNT, SEED_VOTE_STATE_ACCOUNT,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateVoteRoundArgs {
+    pub description: String,
+    pub metadata_uri: String,
+    pub content_hash: [u8; 32],
+    pub voting_end_time: i64,
+    pub voting_mode: VotingMode,
+    pub action: Option<ProposalAction>,
+}
 
 #[derive(Accounts)]
 pub struct CreateVoteRound<'info> {
@@ -21,14 +38,32 @@ pub struct CreateVoteRound<'info> {
 
     pub ft_mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: Creator's voter lock, may not exist. Its escrowed shares count towards the
+    /// proposal threshold.
+    #[account(
+        seeds = [SEED_VOTER_LOCK_ACCOUNT, ft_mint.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub voter_lock: UncheckedAccount<'info>,
+
     #[account(
+        mut,
         has_one = asset,
         has_one = ft_mint,
+        constraint = asset_state.status != AssetStatus::Retired @ ErrorCode::AssetRetired,
+        constraint = !asset_state.share_split_in_progress @ ErrorCode::ShareSplitInProgress,
         seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
         bump = asset_state.bump,
     )]
     pub asset_state: Account<'info, AssetState>,
 
+    #[account(
+        has_one = asset,
+        seeds = [SEED_ASSET_CONFIG_ACCOUNT, asset.key().as_ref()],
+        bump = asset_config.bump,
+    )]
+    pub asset_config: Account<'info, AssetConfig>,
+
     #[account(
         init_if_needed,
         payer = payer,
@@ -51,26 +86,118 @@ pub struct CreateVoteRound<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_create_vote_round(ctx: Context<CreateVoteRound>, description: String) -> Result<()> {
+pub fn handle_create_vote_round(
+    ctx: Context<CreateVoteRound>,
+    args: CreateVoteRoundArgs,
+) -> Result<()> {
+    // Shares escrowed to vote with still belong to the creator
+    let voter_lock = &ctx.accounts.voter_lock;
+    let locked = if voter_lock.owner == ctx.program_id && !voter_lock.data_is_empty() {
+        VoterLock::try_deserialize(&mut &voter_lock.try_borrow_data()?[..])?.amount
+    } else {
+        0
+    };
+    let held = ctx
+        .accounts
+        .token_account
+        .amount
+        .checked_add(locked)
+        .ok_or(ErrorCode::Overflow)?;
+
     //validates signer has ft_mint balance > 0
-    let token_account_data = &ctx.accounts.token_account;
-    if token_account_data.amount == 0 {
+    if held == 0 {
         return Err(ErrorCode::NotTokenBalance.into());
     }
 
+    // validates signer holds the configured share of the asset
+    let total_shares = ctx.accounts.asset_state.total_shares as u128;
+    let asset_config = &ctx.accounts.asset_config;
+    require!(
+        held as u128 * BPS_DENOMINATOR as u128
+            >= asset_config.proposal_threshold_bps as u128 * total_shares,
+        ErrorCode::ProposalThresholdNotMet
+    );
+    let quorum_weight =
+        (total_shares * asset_config.quorum_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    let deposit = asset_config.proposal_deposit;
+
+    let clock = Clock::get()?;
+    require!(
+        args.voting_end_time > clock.unix_timestamp,
+        ErrorCode::InvalidVotingEndTime
+    );
+    require!(
+        args.metadata_uri.len() <= MAX_PROPOSAL_URI_LENGTH,
+        ErrorCode::ProposalUriTooLong
+    );
+    // Quadratic weights can't be compared against the share supermajority a buyout needs
+    if let Some(ProposalAction::Buyout { .. }) = args.action {
+        require!(
+            args.voting_mode == VotingMode::TokenWeighted,
+            ErrorCode::InvalidProposalAction
+        );
+    }
+    // A treasury auction that ends before the round can execute would be over on creation
+    if let Some(ProposalAction::TreasuryAuction {
+        amount,
+        auction_end_time,
+    }) = args.action
+    {
+        require!(
+            amount > 0 && auction_end_time > args.voting_end_time,
+            ErrorCode::InvalidAuctionEndTime
+        );
+    }
+    if let Some(ProposalAction::ShareSplit {
+        numerator,
+        denominator,
+    }) = args.action
+    {
+        require!(
+            numerator > 0 && denominator > 0,
+            ErrorCode::InvalidSplitRatio
+        );
+    }
+
     let vote = &mut ctx.accounts.vote_state;
-    vote.description = description;
+    vote.description = args.description;
+    vote.metadata_uri = args.metadata_uri;
+    vote.content_hash = args.content_hash;
     vote.vote_round = ctx.accounts.vote_round_index.vote_round_count;
     vote.voting_creator = ctx.accounts.payer.key();
     vote.asset = ctx.accounts.asset.key();
     vote.ft_mint = ctx.accounts.ft_mint.key();
     vote.yes_weight = 0;
     vote.no_weight = 0;
+    vote.voting_end_time = args.voting_end_time;
+    vote.voting_mode = args.voting_mode;
+    vote.turnout = 0;
+    vote.quorum_weight = quorum_weight;
+    vote.deposit = deposit;
+    vote.status = ProposalStatus::Active;
+    vote.action = args.action;
     vote.bump = ctx.bumps.vote_state;
 
     let vote_round_count = ctx.accounts.vote_round_index.vote_round_count;
     let vote_round_index = &mut ctx.accounts.vote_round_index;
     vote_round_index.asset = ctx.accounts.asset.key();
     vote_round_index.vote_round_count = vote_round_count + 1;
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_vote_rounds = asset_state
+        .open_vote_rounds
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // The deposit sits on the vote_state account until finalization
+    if deposit > 0 {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: ctx.accounts.vote_state.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        transfer(cpi_ctx, deposit)?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, VoteDelegation};
+use crate::{SEED_STATE_ACCOUNT, SEED_VOTE_DELEGATION_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: Asset account is validated through asset_state PDA
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    // One delegation per holder and asset, re-delegating overwrites the delegate
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = 8 + VoteDelegation::INIT_SPACE,
+        seeds = [SEED_VOTE_DELEGATION_ACCOUNT, asset.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub vote_delegation: Account<'info, VoteDelegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_delegate_vote(ctx: Context<DelegateVote>, delegate: Pubkey) -> Result<()> {
+    require_keys_neq!(
+        delegate,
+        ctx.accounts.delegator.key(),
+        ErrorCode::SelfDelegation
+    );
+
+    let vote_delegation = &mut ctx.accounts.vote_delegation;
+    vote_delegation.asset = ctx.accounts.asset.key();
+    vote_delegation.delegator = ctx.accounts.delegator.key();
+    vote_delegation.delegate = delegate;
+    vote_delegation.bump = ctx.bumps.vote_delegation;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::{AssetState, ProposalStatus, VoteState};
+use crate::{SEED_STATE_ACCOUNT, SEED_TREASURY_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct FinalizeVoteRound<'info> {
+    pub finalizer: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_state has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    /// CHECK: Round creator is validated through vote_state has_one constraint, receives the deposit refund
+    #[account(mut)]
+    pub voting_creator: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        has_one = voting_creator,
+        seeds = [SEED_VOTE_STATE_ACCOUNT, asset.key().as_ref(), vote_state.voting_creator.as_ref(), vote_state.vote_round.to_le_bytes().as_ref()],
+        bump = vote_state.bump,
+    )]
+    pub vote_state: Account<'info, VoteState>,
+
+    #[account(
+        mut,
+        has_one = asset,
+        seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
+        bump = asset_state.bump,
+    )]
+    pub asset_state: Account<'info, AssetState>,
+
+    /// CHECK: Asset treasury PDA, receives deposits of rounds that miss quorum
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_ACCOUNT, asset.key().as_ref()],
+        bump
+    )]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+pub fn handle_finalize_vote_round(ctx: Context<FinalizeVoteRound>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vote_state = &mut ctx.accounts.vote_state;
+
+    require!(
+        clock.unix_timestamp >= vote_state.voting_end_time,
+        ErrorCode::VotingStillActive
+    );
+    require!(
+        vote_state.status == ProposalStatus::Active,
+        ErrorCode::VoteRoundFinalized
+    );
+
+    // Ties and rounds without quorum reject the proposal
+    let quorum_reached = vote_state.turnout >= vote_state.quorum_weight;
+    vote_state.status = if quorum_reached && vote_state.yes_weight > vote_state.no_weight {
+        ProposalStatus::Passed
+    } else {
+        ProposalStatus::Rejected
+    };
+
+    let deposit = vote_state.deposit;
+    if deposit > 0 {
+        vote_state.deposit = 0;
+        let deposit_receiver = if quorum_reached {
+            ctx.accounts.voting_creator.to_account_info()
+        } else {
+            ctx.accounts.treasury.to_account_info()
+        };
+        vote_state.sub_lamports(deposit)?;
+        deposit_receiver.add_lamports(deposit)?;
+    }
+
+    let asset_state = &mut ctx.accounts.asset_state;
+    asset_state.open_vote_rounds = asset_state.open_vote_rounds.saturating_sub(1);
+
+    msg!(
+        "Vote round {} finalized: yes {} / no {}",
+        vote_state.vote_round,
+        vote_state.yes_weight,
+        vote_state.no_weight
+    );
+
+    Ok(())
+}
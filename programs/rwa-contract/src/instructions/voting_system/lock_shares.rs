@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::{create_share_vault, transfer_shares};
+use crate::state::VoterLock;
+use crate::{SEED_VOTER_ESCROW_ACCOUNT, SEED_VOTER_LOCK_ACCOUNT, SEED_VOTER_LOCK_VAULT_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct LockShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VoterLock::INIT_SPACE,
+        seeds = [SEED_VOTER_LOCK_ACCOUNT, ft_mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    /// CHECK: PDA owning every lock vault of the share mint
+    #[account(
+        seeds = [SEED_VOTER_ESCROW_ACCOUNT, ft_mint.key().as_ref()],
+        bump
+    )]
+    pub voter_escrow: UncheckedAccount<'info>,
+
+    /// CHECK: Share vault owned by voter_escrow, created by the handler on the first lock
+    #[account(
+        mut,
+        seeds = [SEED_VOTER_LOCK_VAULT_ACCOUNT, ft_mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub lock_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Moves shares into the holder's voter lock, only locked shares carry voting weight. Transfer
+/// hook accounts of compliance-restricted shares go in `remaining_accounts`.
+pub fn handle_lock_shares<'info>(
+    ctx: Context<'_, '_, 'info, 'info, LockShares<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, ErrorCode::NoShares);
+    require!(
+        ctx.accounts.owner_token_account.amount >= amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+
+    // Only this program can sign for the vault address, so an existing account is the vault
+    // an earlier lock created
+    if ctx.accounts.lock_vault.data_is_empty() {
+        let ft_mint_key = ctx.accounts.ft_mint.key();
+        let owner_key = ctx.accounts.owner.key();
+        let vault_seeds = &[
+            SEED_VOTER_LOCK_VAULT_ACCOUNT,
+            ft_mint_key.as_ref(),
+            owner_key.as_ref(),
+            &[ctx.bumps.lock_vault],
+        ];
+        create_share_vault(
+            &ctx.accounts.owner.to_account_info(),
+            &ctx.accounts.lock_vault.to_account_info(),
+            &ctx.accounts.ft_mint.to_account_info(),
+            &ctx.accounts.voter_escrow.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &[&vault_seeds[..]],
+        )?;
+    }
+
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.lock_vault.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        &[],
+    )?;
+
+    let voter_lock = &mut ctx.accounts.voter_lock;
+    voter_lock.ft_mint = ctx.accounts.ft_mint.key();
+    voter_lock.owner = ctx.accounts.owner.key();
+    voter_lock.amount = voter_lock
+        .amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    voter_lock.bump = ctx.bumps.voter_lock;
+
+    Ok(())
+}
@@ -1,5 +1,26 @@
 pub mod create_vote_round;
 pub use create_vote_round::*;
 
+pub mod lock_shares;
+pub use lock_shares::*;
+
+pub mod unlock_shares;
+pub use unlock_shares::*;
+
 pub mod vote;
 pub use vote::*;
+
+pub mod delegate_vote;
+pub use delegate_vote::*;
+
+pub mod revoke_delegation;
+pub use revoke_delegation::*;
+
+pub mod finalize_vote_round;
+pub use finalize_vote_round::*;
+
+pub mod close_vote_record;
+pub use close_vote_record::*;
+
+pub mod close_vote_round;
+pub use close_vote_round::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VoteDelegation;
+use crate::SEED_VOTE_DELEGATION_ACCOUNT;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    /// CHECK: Asset account is validated through vote_delegation has_one constraint
+    pub asset: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = delegator,
+        has_one = asset,
+        has_one = delegator,
+        seeds = [SEED_VOTE_DELEGATION_ACCOUNT, asset.key().as_ref(), delegator.key().as_ref()],
+        bump = vote_delegation.bump,
+    )]
+    pub vote_delegation: Account<'info, VoteDelegation>,
+}
+
+pub fn handle_revoke_delegation(_ctx: Context<RevokeDelegation>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::error::ErrorCode;
+use crate::instructions::transfer_shares;
+use crate::state::VoterLock;
+use crate::{SEED_VOTER_ESCROW_ACCOUNT, SEED_VOTER_LOCK_ACCOUNT, SEED_VOTER_LOCK_VAULT_ACCOUNT};
+
+#[derive(Accounts)]
+pub struct UnlockShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub ft_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = ft_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = ft_mint,
+        has_one = owner,
+        seeds = [SEED_VOTER_LOCK_ACCOUNT, ft_mint.key().as_ref(), owner.key().as_ref()],
+        bump = voter_lock.bump,
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    /// CHECK: PDA owning every lock vault of the share mint
+    #[account(
+        seeds = [SEED_VOTER_ESCROW_ACCOUNT, ft_mint.key().as_ref()],
+        bump
+    )]
+    pub voter_escrow: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VOTER_LOCK_VAULT_ACCOUNT, ft_mint.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub lock_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Returns locked shares to the holder once every round the lock voted in has ended
+pub fn handle_unlock_shares<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let voter_lock = &mut ctx.accounts.voter_lock;
+    require!(
+        Clock::get()?.unix_timestamp >= voter_lock.locked_until,
+        ErrorCode::SharesStillLocked
+    );
+    require!(
+        amount > 0 && amount <= voter_lock.amount,
+        ErrorCode::InsuficientTokenBalance
+    );
+    voter_lock.amount -= amount;
+
+    let ft_mint_key = ctx.accounts.ft_mint.key();
+    let escrow_seeds = &[
+        SEED_VOTER_ESCROW_ACCOUNT,
+        ft_mint_key.as_ref(),
+        &[ctx.bumps.voter_escrow],
+    ];
+    let signer_seeds = &[&escrow_seeds[..]];
+
+    transfer_shares(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.lock_vault.to_account_info(),
+        &ctx.accounts.ft_mint.to_account_info(),
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.voter_escrow.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.ft_mint.decimals,
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
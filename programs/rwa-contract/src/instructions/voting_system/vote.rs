@@ -1,13 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount};
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_interface::Mint;
 
-use crate::{error::ErrorCode, state::{AssetState, VoteRecord, VoteState}, SEED_STATE_ACCOUNT, SEED_VOTE_RECORD_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum Choice {
-    Yes,
-    No,
-}
+use crate::{error::ErrorCode, state::{AssetState, AssetStatus, VoteDelegation, VoteRecord, VoteState, VoterLock}, SEED_STATE_ACCOUNT, SEED_VOTER_LOCK_ACCOUNT, SEED_VOTE_RECORD_ACCOUNT, SEED_VOTE_STATE_ACCOUNT};
 
 #[derive(Accounts)]
 #[instruction(vote_round_creator: Pubkey, vote_round: u64)]
@@ -25,6 +20,7 @@ pub struct Vote<'info> {
     #[account(
         has_one = asset,
         has_one = ft_mint,
+        constraint = asset_state.status != AssetStatus::Retired @ ErrorCode::AssetRetired,
         seeds = [SEED_STATE_ACCOUNT, asset.key().as_ref()],
         bump = asset_state.bump,
     )]
@@ -40,8 +36,9 @@ pub struct Vote<'info> {
         )]
     pub vote_state: Account<'info, VoteState>,
 
+    // May already exist when a delegate voted on the voter's behalf, see handle_vote
     #[account(
-        init,
+        init_if_needed,
         payer = voter,
         space = 8 + VoteRecord::INIT_SPACE,
         seeds = [
@@ -54,40 +51,180 @@ pub struct Vote<'info> {
     pub vote_record: Account<'info, VoteRecord>,
 
 
+    // Only escrowed shares vote, so the same shares can't be moved to another wallet and vote twice.
+    // Delegates without shares of their own leave it out.
     #[account(
-        token::mint = ft_mint.key(),
-        token::authority = voter.key()
+        mut,
+        has_one = ft_mint,
+        constraint = voter_lock.owner == voter.key() @ ErrorCode::NoShares,
+        seeds = [SEED_VOTER_LOCK_ACCOUNT, ft_mint.key().as_ref(), voter.key().as_ref()],
+        bump = voter_lock.bump,
     )]
-    pub voter_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub voter_lock: Option<Account<'info, VoterLock>>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn handle_vote(ctx: Context<Vote>, _vote_round_creator: Pubkey, _vote_round: u64, choice: u8) -> Result<()> {
+/// Casts the voter's own vote and, when the voter is a delegate, the votes of its delegators.
+///
+/// Votes are weighted by the shares in each holder's `VoterLock`, which then stay locked until the
+/// round ends. Delegated votes are passed through `remaining_accounts` as `[vote_delegation,
+/// delegator_voter_lock, delegator_vote_record]` triplets. Each delegator gets its own
+/// `VoteRecord` marked as cast by the delegate, so a later direct vote can override it.
+pub fn handle_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+    _vote_round_creator: Pubkey,
+    _vote_round: u64,
+    choice: u8,
+) -> Result<()> {
     require!(choice == 0 || choice == 1, ErrorCode::InvalidChoice);
 
-    let weight = ctx.accounts.voter_token_account.amount;
-    require!(weight > 0, ErrorCode::NoShares);
+    let clock = Clock::get()?;
+    require!(clock.unix_timestamp < ctx.accounts.vote_state.voting_end_time, ErrorCode::VotingClosed);
+
+    let voting_end_time = ctx.accounts.vote_state.voting_end_time;
+    let raw_weight = match ctx.accounts.voter_lock.as_mut() {
+        Some(voter_lock) => {
+            voter_lock.locked_until = voter_lock.locked_until.max(voting_end_time);
+            voter_lock.amount
+        }
+        None => 0,
+    };
+    let weight = ctx.accounts.vote_state.effective_weight(raw_weight);
 
     let vote_state = &mut ctx.accounts.vote_state;
     let vote_record = &mut ctx.accounts.vote_record;
+
+    // An existing record is only replaceable when a delegate cast it on the voter's behalf
+    if vote_record.voter != Pubkey::default() {
+        require_keys_neq!(vote_record.cast_by, vote_record.voter, ErrorCode::AlreadyVoted);
+        vote_state.remove_vote(vote_record.choice, vote_record.raw_weight, vote_record.weight)?;
+    }
+
     vote_record.vote_state = vote_state.key();
     vote_record.voter = ctx.accounts.voter.key();
+    vote_record.cast_by = ctx.accounts.voter.key();
     vote_record.choice = choice;
+    vote_record.raw_weight = raw_weight;
     vote_record.weight = weight;
     vote_record.bump = ctx.bumps.vote_record;
+    vote_state.add_vote(choice, raw_weight, weight)?;
 
-    if choice == 1 {
-        vote_state.yes_weight = vote_state
-            .yes_weight
-            .checked_add(weight)
-            .ok_or(ErrorCode::Overflow)?;
-    } else {
-        vote_state.no_weight = vote_state
-            .no_weight
-            .checked_add(weight)
+    let mut delegated_weight: u64 = 0;
+    for accounts in ctx.remaining_accounts.chunks(3) {
+        let [delegation_info, lock_info, record_info] = accounts else {
+            return Err(ErrorCode::InvalidDelegation.into());
+        };
+
+        let delegation = Account::<VoteDelegation>::try_from(delegation_info)?;
+        require_keys_eq!(delegation.asset, vote_state.asset, ErrorCode::InvalidDelegation);
+        require_keys_eq!(delegation.delegate, ctx.accounts.voter.key(), ErrorCode::InvalidDelegation);
+
+        let mut delegator_lock = Account::<VoterLock>::try_from(lock_info)?;
+        require_keys_eq!(delegator_lock.ft_mint, vote_state.ft_mint, ErrorCode::InvalidMint);
+        require_keys_eq!(delegator_lock.owner, delegation.delegator, ErrorCode::InvalidDelegation);
+
+        let vote_state_key = vote_state.key();
+        let (record_key, record_bump) = Pubkey::find_program_address(
+            &[SEED_VOTE_RECORD_ACCOUNT, vote_state_key.as_ref(), delegation.delegator.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(record_info.key(), record_key, ErrorCode::InvalidDelegation);
+
+        // A delegator that already voted directly keeps its own choice
+        require!(record_info.data_is_empty(), ErrorCode::DelegatorAlreadyVoted);
+
+        let record_seeds: &[&[u8]] = &[
+            SEED_VOTE_RECORD_ACCOUNT,
+            vote_state_key.as_ref(),
+            delegation.delegator.as_ref(),
+            &[record_bump],
+        ];
+        create_vote_record_account(
+            &ctx.accounts.voter.to_account_info(),
+            record_info,
+            &ctx.accounts.system_program.to_account_info(),
+            ctx.program_id,
+            &[record_seeds],
+        )?;
+
+        // Each delegator's shares go through the voting mode on their own
+        let delegator_raw_weight = delegator_lock.amount;
+        let delegator_weight = vote_state.effective_weight(delegator_raw_weight);
+        let delegator_record = VoteRecord {
+            vote_state: vote_state_key,
+            voter: delegation.delegator,
+            cast_by: ctx.accounts.voter.key(),
+            choice,
+            raw_weight: delegator_raw_weight,
+            weight: delegator_weight,
+            bump: record_bump,
+        };
+        delegator_record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+
+        vote_state.add_vote(choice, delegator_raw_weight, delegator_weight)?;
+
+        // The delegator's shares stay locked for the round like the delegate's own
+        delegator_lock.locked_until = delegator_lock.locked_until.max(voting_end_time);
+        delegator_lock.exit(ctx.program_id)?;
+        delegated_weight = delegated_weight
+            .checked_add(delegator_weight)
             .ok_or(ErrorCode::Overflow)?;
     }
 
+    require!(
+        weight.checked_add(delegated_weight).ok_or(ErrorCode::Overflow)? > 0,
+        ErrorCode::NoShares
+    );
+
     Ok(())
 }
+
+// Creates a program-owned vote record PDA, tolerating lamports that were sent to the address beforehand.
+// Delegator records arrive through `remaining_accounts` in a variable number, so Anchor's `init`
+// constraint can't create them and the system program calls are made by hand.
+fn create_vote_record_account<'info>(
+    payer: &AccountInfo<'info>,
+    record: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let space = 8 + VoteRecord::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = record.lamports();
+
+    if current_lamports == 0 {
+        let cpi_accounts = CreateAccount {
+            from: payer.clone(),
+            to: record.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(system_program.clone(), cpi_accounts, signer_seeds);
+        return create_account(cpi_ctx, rent, space as u64, program_id);
+    }
+
+    let top_up = rent.saturating_sub(current_lamports);
+    if top_up > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: record.clone(),
+        };
+        transfer(CpiContext::new(system_program.clone(), cpi_accounts), top_up)?;
+    }
+
+    let allocate_accounts = Allocate {
+        account_to_allocate: record.clone(),
+    };
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), allocate_accounts, signer_seeds),
+        space as u64,
+    )?;
+
+    let assign_accounts = Assign {
+        account_to_assign: record.clone(),
+    };
+    assign(
+        CpiContext::new_with_signer(system_program.clone(), assign_accounts, signer_seeds),
+        program_id,
+    )
+}
@@ -18,12 +18,25 @@ pub mod rwa_contract {
         initialize::handler(ctx)
     }
 
-    pub fn create_fungible_token(
-        ctx: Context<CreateFungibleToken>,
+    pub fn create_fungible_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateFungibleToken<'info>>,
         decimals: u8,
-        supply: u8,
+        supply: u64,
+        allocations: Vec<ShareAllocation>,
+        freeze_asset: Option<Pubkey>,
     ) -> Result<()> {
-        create_fungible_token_and_revoke_authority(ctx, decimals, supply)
+        create_fungible_token_and_revoke_authority(ctx, decimals, supply, allocations, freeze_asset)
+    }
+
+    pub fn create_compliant_fungible_token<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCompliantFungibleToken<'info>>,
+        decimals: u8,
+        supply: u64,
+        allocations: Vec<ShareAllocation>,
+        asset: Pubkey,
+        metadata: ShareMetadataArgs,
+    ) -> Result<()> {
+        handle_create_compliant_fungible_token(ctx, decimals, supply, allocations, asset, metadata)
     }
 
     pub fn create_non_fungible_token(
@@ -33,27 +46,285 @@ pub mod rwa_contract {
         handle_create_non_fungible_token(ctx, args)
     }
 
-    pub fn create_vote_round(ctx: Context<CreateVoteRound>, description: String) -> Result<()> {
-        handle_create_vote_round(ctx, description)
+    pub fn tokenize_property<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TokenizeProperty<'info>>,
+        args: TokenizePropertyArgs,
+    ) -> Result<()> {
+        handle_tokenize_property(ctx, args)
+    }
+
+    pub fn update_property_metadata(
+        ctx: Context<UpdatePropertyMetadata>,
+        args: UpdatePropertyMetadataArgs,
+    ) -> Result<()> {
+        handle_update_property_metadata(ctx, args)
+    }
+
+    pub fn update_property_attribute(
+        ctx: Context<UpdatePropertyAttribute>,
+        key: String,
+        value: String,
+    ) -> Result<()> {
+        handle_update_property_attribute(ctx, key, value)
+    }
+
+    pub fn update_asset_config(
+        ctx: Context<UpdateAssetConfig>,
+        args: UpdateAssetConfigArgs,
+    ) -> Result<()> {
+        handle_update_asset_config(ctx, args)
+    }
+
+    pub fn freeze_holder(ctx: Context<HolderFreeze>) -> Result<()> {
+        handle_freeze_holder(ctx)
     }
 
-    pub fn vote(ctx: Context<Vote>, vote_round_creator: Pubkey, vote_round: u64, choice: u8) -> Result<()> {
+    pub fn thaw_holder(ctx: Context<HolderFreeze>) -> Result<()> {
+        handle_thaw_holder(ctx)
+    }
+
+    pub fn register_investor(ctx: Context<InvestorRegistry>, wallet: Pubkey) -> Result<()> {
+        handle_register_investor(ctx, wallet)
+    }
+
+    pub fn unregister_investor(ctx: Context<InvestorRegistry>) -> Result<()> {
+        handle_unregister_investor(ctx)
+    }
+
+    pub fn register_program_account(
+        ctx: Context<InvestorRegistry>,
+        account: ProgramShareAccount,
+    ) -> Result<()> {
+        handle_register_program_account(ctx, account)
+    }
+
+    pub fn redeem_property(ctx: Context<RedeemProperty>) -> Result<()> {
+        handle_redeem_property(ctx)
+    }
+
+    pub fn fund_buyout(ctx: Context<FundBuyout>) -> Result<()> {
+        handle_fund_buyout(ctx)
+    }
+
+    pub fn claim_buyout(ctx: Context<ClaimBuyout>) -> Result<()> {
+        handle_claim_buyout(ctx)
+    }
+
+    pub fn claim_treasury_buyout(ctx: Context<ClaimTreasuryBuyout>) -> Result<()> {
+        handle_claim_treasury_buyout(ctx)
+    }
+
+    pub fn claim_auction_buyout(ctx: Context<ClaimAuctionBuyout>) -> Result<()> {
+        handle_claim_auction_buyout(ctx)
+    }
+
+    pub fn complete_buyout(ctx: Context<CompleteBuyout>) -> Result<()> {
+        handle_complete_buyout(ctx)
+    }
+
+    pub fn create_portfolio(
+        ctx: Context<CreatePortfolio>,
+        args: CreatePortfolioArgs,
+    ) -> Result<()> {
+        handle_create_portfolio(ctx, args)
+    }
+
+    pub fn retire_asset(ctx: Context<RetireAsset>) -> Result<()> {
+        handle_retire_asset(ctx)
+    }
+
+    pub fn deposit_distribution(
+        ctx: Context<DepositDistribution>,
+        period: u64,
+        amount: u64,
+        snapshot_root: [u8; 32],
+    ) -> Result<()> {
+        handle_deposit_distribution(ctx, period, amount, snapshot_root)
+    }
+
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        index: u32,
+        shares: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_distribution(ctx, index, shares, proof)
+    }
+
+    pub fn sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+        handle_sweep_distribution(ctx)
+    }
+
+    pub fn create_merkle_distribution(
+        ctx: Context<CreateMerkleDistribution>,
+        args: CreateMerkleDistributionArgs,
+    ) -> Result<()> {
+        handle_create_merkle_distribution(ctx, args)
+    }
+
+    pub fn claim_merkle_distribution(
+        ctx: Context<ClaimMerkleDistribution>,
+        index: u32,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_merkle_distribution(ctx, index, amount, proof)
+    }
+
+    pub fn deposit_treasury(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
+        handle_deposit_treasury(ctx, amount)
+    }
+
+    pub fn spend_treasury_allowance(
+        ctx: Context<SpendTreasuryAllowance>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_spend_treasury_allowance(ctx, amount)
+    }
+
+    pub fn run_waterfall(
+        ctx: Context<RunWaterfall>,
+        period: u64,
+        snapshot_root: [u8; 32],
+    ) -> Result<()> {
+        handle_run_waterfall(ctx, period, snapshot_root)
+    }
+
+    pub fn create_vote_round(ctx: Context<CreateVoteRound>, args: CreateVoteRoundArgs) -> Result<()> {
+        handle_create_vote_round(ctx, args)
+    }
+
+    pub fn lock_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LockShares<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_lock_shares(ctx, amount)
+    }
+
+    pub fn unlock_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_unlock_shares(ctx, amount)
+    }
+
+    pub fn vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Vote<'info>>,
+        vote_round_creator: Pubkey,
+        vote_round: u64,
+        choice: u8,
+    ) -> Result<()> {
         handle_vote(ctx, vote_round_creator, vote_round, choice)
     }
 
-    pub fn create_auction(
-        ctx: Context<CreateAuction>,
+    pub fn delegate_vote(ctx: Context<DelegateVote>, delegate: Pubkey) -> Result<()> {
+        handle_delegate_vote(ctx, delegate)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        handle_revoke_delegation(ctx)
+    }
+
+    pub fn finalize_vote_round(ctx: Context<FinalizeVoteRound>) -> Result<()> {
+        handle_finalize_vote_round(ctx)
+    }
+
+    pub fn close_vote_record(ctx: Context<CloseVoteRecord>) -> Result<()> {
+        handle_close_vote_record(ctx)
+    }
+
+    pub fn close_vote_round(ctx: Context<CloseVoteRound>) -> Result<()> {
+        handle_close_vote_round(ctx)
+    }
+
+    pub fn create_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateAuction<'info>>,
+        auction_id: u64,
         amount: u64,
         auction_end_time: i64,
+        rofr: Option<RofrTerms>,
     ) -> Result<()> {
-        handle_create_auction(ctx, amount, auction_end_time)
+        handle_create_auction(ctx, auction_id, amount, auction_end_time, rofr)
     }
 
     pub fn place_bid(ctx: Context<PlaceBid>, bid_amount: u64) -> Result<()> {
         handle_place_bid(ctx, bid_amount)
     }
 
-    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+    pub fn join_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, JoinAuction<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        handle_join_auction(ctx, amount)
+    }
+
+    pub fn exercise_rofr<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExerciseRofr<'info>>,
+    ) -> Result<()> {
+        handle_exercise_rofr(ctx)
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+    ) -> Result<()> {
         handle_settle_auction(ctx)
     }
+
+    pub fn refund_contribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundContribution<'info>>,
+    ) -> Result<()> {
+        handle_refund_contribution(ctx)
+    }
+
+    pub fn reclaim_unsold_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReclaimUnsoldShares<'info>>,
+    ) -> Result<()> {
+        handle_reclaim_unsold_shares(ctx)
+    }
+
+    pub fn execute_share_split(ctx: Context<ExecuteShareSplit>) -> Result<()> {
+        handle_execute_share_split(ctx)
+    }
+
+    pub fn claim_share_split(ctx: Context<ClaimShareSplit>) -> Result<()> {
+        handle_claim_share_split(ctx)
+    }
+
+    pub fn claim_treasury_share_split(ctx: Context<ClaimTreasuryShareSplit>) -> Result<()> {
+        handle_claim_treasury_share_split(ctx)
+    }
+
+    pub fn finish_share_split(ctx: Context<FinishShareSplit>) -> Result<()> {
+        handle_finish_share_split(ctx)
+    }
+
+    pub fn queue_proposal(ctx: Context<QueueProposal>) -> Result<()> {
+        handle_queue_proposal(ctx)
+    }
+
+    pub fn cancel_queued_action(ctx: Context<CancelQueuedAction>) -> Result<()> {
+        handle_cancel_queued_action(ctx)
+    }
+
+    pub fn execute_treasury_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteTreasuryAuction<'info>>,
+    ) -> Result<()> {
+        handle_execute_treasury_auction(ctx)
+    }
+
+    pub fn execute_treasury_payment(ctx: Context<ExecuteTreasuryPayment>) -> Result<()> {
+        handle_execute_treasury_payment(ctx)
+    }
+
+    pub fn execute_config_update(ctx: Context<ExecuteConfigUpdate>) -> Result<()> {
+        handle_execute_config_update(ctx)
+    }
+
+    pub fn execute_treasury_config_update(ctx: Context<ExecuteTreasuryConfigUpdate>) -> Result<()> {
+        handle_execute_treasury_config_update(ctx)
+    }
+
+    pub fn close_queued_action(ctx: Context<CloseQueuedAction>) -> Result<()> {
+        handle_close_queued_action(ctx)
+    }
 }
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::{
+    DEFAULT_ALLOWANCE_PERIOD, DEFAULT_PROPOSAL_THRESHOLD_BPS, DEFAULT_QUORUM_BPS,
+    DEFAULT_SUPERMAJORITY_BPS, DEFAULT_TAG_ALONG_THRESHOLD_BPS, DEFAULT_TAG_ALONG_WINDOW,
+    DEFAULT_TIMELOCK_DELAY,
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct AssetConfig {
+    pub asset: Pubkey,
+
+    /// Wallet allowed to appoint the compliance officer through `update_asset_config`
+    pub authority: Pubkey,
+
+    /// Wallet allowed to cancel queued proposals during the timelock
+    pub guardian: Pubkey,
+
+    /// Wallet allowed to freeze and thaw share holders without a vote
+    pub compliance_officer: Pubkey,
+
+    /// Wallet allowed to update the property NFT metadata without a vote and deposit rental income
+    pub asset_manager: Pubkey,
+
+    /// Seconds a passed proposal waits in the queue before it can execute
+    pub timelock_delay: i64,
+
+    /// Share of `AssetState.total_shares`, in basis points, needed to create a vote round
+    pub proposal_threshold_bps: u16,
+
+    /// Lamports locked when creating a vote round, refunded if the round reaches quorum
+    pub proposal_deposit: u64,
+
+    /// Share of `AssetState.total_shares`, in basis points, that must vote for a round to pass
+    pub quorum_bps: u16,
+
+    /// Share of `AssetState.total_shares`, in basis points, that must vote YES on a buyout
+    pub supermajority_bps: u16,
+
+    /// Share of `AssetState.total_shares`, in basis points, an auction must exceed before other
+    /// holders can join it
+    pub tag_along_threshold_bps: u16,
+
+    /// Seconds other holders have to join an auction above the tag-along threshold
+    pub tag_along_window: i64,
+
+    /// USDC base units the asset manager can spend from the treasury per period without a vote
+    pub manager_allowance: u64,
+
+    /// Seconds after which the manager's spending resets
+    pub allowance_period: i64,
+
+    /// USDC base units the waterfall keeps in the treasury as operating reserve
+    pub reserve_target: u64,
+
+    /// Share of the income above the reserve, in basis points, paid to the asset manager
+    pub management_fee_bps: u16,
+
+    /// USDC base units paid to `debt_service_recipient` each time the waterfall runs
+    pub debt_service_amount: u64,
+
+    /// Lender paid by the waterfall, the default key when the property has no debt
+    pub debt_service_recipient: Pubkey,
+
+    pub bump: u8,
+}
+
+impl AssetConfig {
+    /// The tokenizer administers the asset until it hands the authority over. The timelock delay
+    /// and tag-along window are only chosen here, afterwards it takes an `UpdateConfig` proposal
+    /// to change them.
+    pub fn initialize(
+        &mut self,
+        asset: Pubkey,
+        tokenizer: Pubkey,
+        timelock_delay: Option<i64>,
+        tag_along_window: Option<i64>,
+        bump: u8,
+    ) -> Result<()> {
+        let timelock_delay = timelock_delay.unwrap_or(DEFAULT_TIMELOCK_DELAY);
+        require!(timelock_delay >= 0, ErrorCode::InvalidTimelockDelay);
+        let tag_along_window = tag_along_window.unwrap_or(DEFAULT_TAG_ALONG_WINDOW);
+        require!(tag_along_window >= 0, ErrorCode::InvalidTagAlongWindow);
+
+        self.asset = asset;
+        self.authority = tokenizer;
+        self.guardian = tokenizer;
+        self.compliance_officer = tokenizer;
+        self.asset_manager = tokenizer;
+        self.timelock_delay = timelock_delay;
+        self.proposal_threshold_bps = DEFAULT_PROPOSAL_THRESHOLD_BPS;
+        self.proposal_deposit = 0;
+        self.quorum_bps = DEFAULT_QUORUM_BPS;
+        self.supermajority_bps = DEFAULT_SUPERMAJORITY_BPS;
+        self.tag_along_threshold_bps = DEFAULT_TAG_ALONG_THRESHOLD_BPS;
+        self.tag_along_window = tag_along_window;
+        self.manager_allowance = 0;
+        self.allowance_period = DEFAULT_ALLOWANCE_PERIOD;
+        self.reserve_target = 0;
+        self.management_fee_bps = 0;
+        self.debt_service_amount = 0;
+        self.debt_service_recipient = Pubkey::default();
+        self.bump = bump;
+        Ok(())
+    }
+}
@@ -1,10 +1,49 @@
 use anchor_lang::prelude::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AssetStatus {
+    /// Shares are in circulation and the NFT is held by the owner PDA
+    Active,
+
+    /// A forced buyout was funded and the NFT handed to the buyer, holders are still claiming
+    BuyoutPending,
+
+    /// All shares were burned and the NFT was handed to the redeemer
+    Redeemed,
+
+    /// The property was sold off-chain or demolished and its NFT burned
+    Retired,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct AssetState {
     pub asset: Pubkey,
     pub ft_mint: Pubkey,
     pub total_shares: u64,
+
+    /// Portfolio collection the property NFT belongs to
+    pub collection: Option<Pubkey>,
+    pub status: AssetStatus,
+
+    /// Auctions and vote rounds still running on the current share mint, a share split waits
+    /// for both to reach zero
+    pub open_auctions: u32,
+    pub open_vote_rounds: u32,
+
+    /// USDC base units of queued treasury payments, the waterfall and the manager's allowance
+    /// leave them in the treasury until the payment executes or is canceled
+    pub committed_usdc: u64,
+
+    /// USDC base units paid in through `deposit_treasury` since the last waterfall, the only
+    /// part of the treasury the management fee and debt service are taken from
+    pub treasury_income: u64,
+
+    /// When the waterfall last ran, 0 before the first run
+    pub last_waterfall_time: i64,
+
+    /// Set while holders migrate to the mint of a share split, `total_shares` already counts
+    /// the new shares. Auctions, vote rounds and distributions wait for `finish_share_split`.
+    pub share_split_in_progress: bool,
     pub bump: u8,
 }
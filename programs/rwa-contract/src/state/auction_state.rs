@@ -5,11 +5,28 @@ use anchor_lang::prelude::*;
 pub struct AuctionState {
     pub asset: Pubkey,
     pub auction_creator: Pubkey,
+    pub auction_id: u64,        // Lets a creator run several auctions
     pub ft_mint: Pubkey,        // Mint of the tokenized asset being auctioned
     pub bid_token_mint: Pubkey, // USDC mint address for bids
     pub is_active: bool,
     pub highest_bid: u64,
     pub highest_bidder: Pubkey,
     pub auction_end_time: i64,
+    pub total_amount: u64,      // Shares deposited by all sellers
+    pub join_end_time: i64,     // End of the tag-along window and start of bidding, 0 if none
+    pub contributor_count: u32, // Tag-along sellers that joined
+    pub rofr_end_time: i64,     // Only share holders can bid until then, 0 if none
+    pub asking_price: u64,      // USDC price holders can buy at during the ROFR window
+    pub bump: u8,
+}
+
+/// Shares a tag-along seller added to someone else's auction
+#[account]
+#[derive(InitSpace)]
+pub struct AuctionContribution {
+    pub auction_state: Pubkey,
+    pub seller: Pubkey,
+    pub amount: u64,
+    pub paid: bool,
     pub bump: u8,
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Funded forced buyout of an asset, owns the USDC vault holders claim from
+#[account]
+#[derive(InitSpace)]
+pub struct BuyoutState {
+    pub asset: Pubkey,
+    pub buyer: Pubkey,
+
+    /// Round that approved the buyout
+    pub vote_state: Pubkey,
+    pub usdc_mint: Pubkey,
+
+    /// USDC base units paid per whole share
+    pub price_per_share: u64,
+
+    /// Share base units not claimed yet, the buyout completes when this reaches zero
+    pub remaining_shares: u64,
+    pub bump: u8,
+}
+
+impl BuyoutState {
+    /// USDC owed for `shares` base units of a mint with `share_decimals`, rounded down
+    pub fn payment_for(price_per_share: u64, shares: u64, share_decimals: u8) -> Result<u64> {
+        let unit = 10u128
+            .checked_pow(share_decimals as u32)
+            .ok_or(ErrorCode::Overflow)?;
+        let payment = shares as u128 * price_per_share as u128 / unit;
+        u64::try_from(payment).map_err(|_| ErrorCode::Overflow.into())
+    }
+}
@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::error::ErrorCode;
+
+/// Rental income deposited for one period, owns the USDC vault holders claim from
+#[account]
+#[derive(InitSpace)]
+pub struct Distribution {
+    pub asset: Pubkey,
+
+    /// Share mint whose balances entitle holders to claim
+    pub ft_mint: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub depositor: Pubkey,
+
+    /// Period identifier chosen by the asset manager, e.g. 202610 for October 2026
+    pub period: u64,
+
+    /// USDC base units deposited for the period
+    pub amount: u64,
+
+    /// `AssetState.total_shares` when the income was deposited
+    pub total_shares: u64,
+
+    /// Root of the tree built from `merkle_leaf_hash(index, holder, shares)` over
+    /// the share balances when the income was deposited. Shares moved after the snapshot
+    /// can't claim again from another wallet.
+    pub snapshot_root: [u8; 32],
+
+    /// Claims close at this time and whatever is left can be swept to the treasury
+    pub claim_end_time: i64,
+
+    /// USDC base units already paid out
+    pub claimed: u64,
+
+    /// Snapshot shares already paid for, a snapshot listing more than `total_shares` can't pay
+    /// out past them
+    pub claimed_shares: u64,
+
+    /// USDC base units the waterfall paid to `debt_service_recipient` for the period, 0 for
+    /// income deposited with `deposit_distribution`
+    pub debt_service: u64,
+    pub debt_service_recipient: Pubkey,
+    pub bump: u8,
+}
+
+impl Distribution {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        asset: Pubkey,
+        ft_mint: Pubkey,
+        usdc_mint: Pubkey,
+        depositor: Pubkey,
+        period: u64,
+        amount: u64,
+        total_shares: u64,
+        snapshot_root: [u8; 32],
+        claim_end_time: i64,
+        bump: u8,
+    ) -> Result<()> {
+        // Payouts are pro rata to the share supply
+        require!(total_shares > 0, ErrorCode::NoSharesOutstanding);
+
+        self.asset = asset;
+        self.ft_mint = ft_mint;
+        self.usdc_mint = usdc_mint;
+        self.depositor = depositor;
+        self.period = period;
+        self.amount = amount;
+        self.total_shares = total_shares;
+        self.snapshot_root = snapshot_root;
+        self.claim_end_time = claim_end_time;
+        self.claimed = 0;
+        self.claimed_shares = 0;
+        self.debt_service = 0;
+        self.debt_service_recipient = Pubkey::default();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Checks that `holder` held `shares` at leaf `index` of the snapshot
+    pub fn verify_snapshot(
+        &self,
+        proof: &[[u8; 32]],
+        index: u32,
+        holder: &Pubkey,
+        shares: u64,
+    ) -> bool {
+        let leaf = merkle_leaf_hash(index, holder, shares);
+        verify_merkle_proof(&self.snapshot_root, proof, leaf)
+    }
+
+    /// USDC owed for `shares` base units, rounded down and capped by what is left in the vault
+    pub fn payout_for(&self, shares: u64) -> Result<u64> {
+        let payout = (shares as u128 * self.amount as u128)
+            .checked_div(self.total_shares as u128)
+            .ok_or(ErrorCode::NoSharesOutstanding)?;
+        let remaining = self
+            .amount
+            .checked_sub(self.claimed)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(u64::try_from(payout)
+            .map_err(|_| ErrorCode::Overflow)?
+            .min(remaining))
+    }
+
+    /// Books a claim of `payout` USDC for `shares` snapshot shares
+    pub fn record_claim(&mut self, shares: u64, payout: u64) -> Result<()> {
+        self.claimed_shares = self
+            .claimed_shares
+            .checked_add(shares)
+            .ok_or(ErrorCode::Overflow)?;
+        require!(
+            self.claimed_shares <= self.total_shares,
+            ErrorCode::SnapshotExceedsSupply
+        );
+        self.claimed = self
+            .claimed
+            .checked_add(payout)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+}
+
+/// Leaves are prefixed with 0x00 and nodes with 0x01 so a node can't pass as a leaf
+pub fn merkle_leaf_hash(index: u32, holder: &Pubkey, amount: u64) -> [u8; 32] {
+    hashv(&[
+        &[0u8],
+        index.to_le_bytes().as_ref(),
+        holder.as_ref(),
+        amount.to_le_bytes().as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Siblings are hashed in sorted order, so proofs don't need to carry left/right flags
+pub fn verify_merkle_proof(root: &[u8; 32], proof: &[[u8; 32]], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        hashv(&[&[1u8], left.as_ref(), right.as_ref()]).to_bytes()
+    });
+    computed == *root
+}
+
+/// Marks a snapshot leaf as paid for a distribution
+#[account]
+#[derive(InitSpace)]
+pub struct DistributionClaim {
+    pub distribution: Pubkey,
+
+    /// Leaf of the snapshot that was paid
+    pub index: u32,
+    pub holder: Pubkey,
+
+    /// Shares held at the snapshot
+    pub shares: u64,
+
+    /// USDC base units paid to the holder
+    pub amount: u64,
+    pub bump: u8,
+}
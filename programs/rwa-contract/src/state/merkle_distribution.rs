@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::verify_merkle_proof;
+
+/// USDC distribution epoch whose (holder, amount) allocations are committed to by a merkle root
+#[account]
+#[derive(InitSpace)]
+pub struct MerkleDistribution {
+    pub asset: Pubkey,
+    pub distributor: Pubkey,
+    pub usdc_mint: Pubkey,
+
+    /// Epoch identifier chosen by the distributor
+    pub epoch: u64,
+
+    /// Root of the tree built from `merkle_leaf_hash(index, holder, amount)`
+    pub merkle_root: [u8; 32],
+
+    /// Number of leaves in the tree, one claim bit per leaf
+    pub num_leaves: u32,
+
+    /// USDC base units funded for the epoch
+    pub total_amount: u64,
+
+    /// USDC base units already paid out
+    pub claimed_amount: u64,
+    pub bump: u8,
+}
+
+impl MerkleDistribution {
+    pub fn verify(&self, proof: &[[u8; 32]], leaf: [u8; 32]) -> bool {
+        verify_merkle_proof(&self.merkle_root, proof, leaf)
+    }
+}
+
+/// One bit per leaf of a merkle distribution, set once the leaf is claimed
+#[account]
+pub struct MerkleClaims {
+    pub merkle_distribution: Pubkey,
+    pub bitmap: Vec<u8>,
+}
+
+impl MerkleClaims {
+    pub fn space(num_leaves: u32) -> usize {
+        8 + 32 + 4 + (num_leaves as usize).div_ceil(8)
+    }
+
+    /// Fails if the leaf was already claimed
+    pub fn set_claimed(&mut self, index: u32) -> Result<()> {
+        let byte = self
+            .bitmap
+            .get_mut(index as usize / 8)
+            .ok_or(ErrorCode::InvalidMerkleProof)?;
+        let mask = 1u8 << (index % 8);
+        require!(*byte & mask == 0, ErrorCode::AlreadyClaimed);
+        *byte |= mask;
+        Ok(())
+    }
+}
@@ -12,3 +12,36 @@ pub use vote_record::*;
 
 pub mod auction_state;
 pub use auction_state::*;
+
+pub mod vote_delegation;
+pub use vote_delegation::*;
+
+pub mod voter_lock;
+pub use voter_lock::*;
+
+pub mod asset_config;
+pub use asset_config::*;
+
+pub mod queued_action;
+pub use queued_action::*;
+
+pub mod vote_result;
+pub use vote_result::*;
+
+pub mod buyout_state;
+pub use buyout_state::*;
+
+pub mod portfolio_state;
+pub use portfolio_state::*;
+
+pub mod share_split;
+pub use share_split::*;
+
+pub mod distribution;
+pub use distribution::*;
+
+pub mod merkle_distribution;
+pub use merkle_distribution::*;
+
+pub mod treasury_allowance;
+pub use treasury_allowance::*;
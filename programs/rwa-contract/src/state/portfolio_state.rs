@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Fund of properties grouped in an mpl-core collection. This PDA is the collection's update
+/// authority, so only the program can add assets to it.
+#[account]
+#[derive(InitSpace)]
+pub struct PortfolioState {
+    pub collection: Pubkey,
+
+    /// Wallet allowed to tokenize properties into the portfolio
+    pub manager: Pubkey,
+    pub bump: u8,
+}
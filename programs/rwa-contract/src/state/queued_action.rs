@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedAction {
+    /// The passed vote round whose action is queued
+    pub vote_state: Pubkey,
+
+    pub asset: Pubkey,
+
+    pub queued_at: i64,
+
+    /// Earliest time the action can execute
+    pub eta: i64,
+
+    /// Wallet that paid the rent, refunded by `close_queued_action`
+    pub payer: Pubkey,
+
+    pub bump: u8,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Migration of an asset's holders from `old_mint` to `new_mint` after a passed split
+#[account]
+#[derive(InitSpace)]
+pub struct ShareSplit {
+    pub asset: Pubkey,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+
+    /// Every `denominator` old shares become `numerator` new shares
+    pub numerator: u32,
+    pub denominator: u32,
+
+    /// Old share base units not migrated yet, `finish_share_split` revokes the new mint
+    /// authority once they reach zero
+    pub remaining_old_shares: u64,
+
+    /// New share base units the old supply converts to as a whole
+    pub expected_new_shares: u64,
+
+    /// New share base units minted so far, rounding each claim down leaves the difference to
+    /// `expected_new_shares` as dust
+    pub minted_new_shares: u64,
+    pub bump: u8,
+}
+
+impl ShareSplit {
+    /// New share base units for `old_shares`, rounded down
+    pub fn convert(&self, old_shares: u64) -> Result<u64> {
+        let new_shares = old_shares as u128 * self.numerator as u128 / self.denominator as u128;
+        u64::try_from(new_shares).map_err(|_| ErrorCode::Overflow.into())
+    }
+
+    /// Records the migration of `old_shares` and returns the new share base units to mint
+    pub fn record_claim(&mut self, old_shares: u64) -> Result<u64> {
+        let new_shares = self.convert(old_shares)?;
+        self.remaining_old_shares = self
+            .remaining_old_shares
+            .checked_sub(old_shares)
+            .ok_or(ErrorCode::Overflow)?;
+        self.minted_new_shares = self
+            .minted_new_shares
+            .checked_add(new_shares)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(new_shares)
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Treasury spending by the asset manager in the current allowance period
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryAllowance {
+    pub asset: Pubkey,
+
+    /// Start of the current period, zero before the first spend
+    pub period_start: i64,
+
+    /// USDC base units spent since `period_start`
+    pub spent: u64,
+    pub bump: u8,
+}
+
+impl TreasuryAllowance {
+    /// Starts a new period once the current one has elapsed, then records `amount` against the
+    /// allowance
+    pub fn spend(&mut self, amount: u64, allowance: u64, period: i64, now: i64) -> Result<()> {
+        if now >= self.period_start.saturating_add(period) {
+            self.period_start = now;
+            self.spent = 0;
+        }
+
+        let spent = self.spent.checked_add(amount).ok_or(ErrorCode::Overflow)?;
+        require!(spent <= allowance, ErrorCode::AllowanceExceeded);
+        self.spent = spent;
+        Ok(())
+    }
+}
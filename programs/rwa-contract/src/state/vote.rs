@@ -1,16 +1,209 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::state::QueuedAction;
+use crate::PROPOSAL_QUEUE_WINDOW;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum VotingMode {
+    /// One share, one vote
+    TokenWeighted,
+
+    /// Votes count with the integer square root of the voter's shares
+    Quadratic,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalStatus {
+    Active,
+    Passed,
+    Rejected,
+    Queued,
+    Executed,
+    Canceled,
+}
+
+/// On-chain action a vote round carries, run by the matching `execute_*` instruction once the round passed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ProposalAction {
+    /// Auction `amount` share tokens held by the asset treasury
+    TreasuryAuction { amount: u64, auction_end_time: i64 },
+
+    /// Rename the property NFT and/or point it to new off-chain metadata
+    UpdatePropertyMetadata {
+        #[max_len(64)]
+        name: Option<String>,
+        #[max_len(200)]
+        uri: Option<String>,
+    },
+
+    /// Pay `amount` USDC from the asset treasury to `recipient`
+    TreasuryPayment { recipient: Pubkey, amount: u64 },
+
+    /// Cancel the queued action of another round, takes effect without a timelock
+    CancelQueuedAction { vote_state: Pubkey },
+
+    /// Freeze a share token account, e.g. for a regulatory hold
+    FreezeHolder { token_account: Pubkey },
+
+    /// Lift the freeze of a share token account
+    ThawHolder { token_account: Pubkey },
+
+    /// Force a sale of all shares to `buyer` at `price_per_share` USDC base units per whole
+    /// share, needs a supermajority of all shares in a token-weighted round
+    Buyout { buyer: Pubkey, price_per_share: u64 },
+
+    /// Burn the property NFT and retire the asset after an off-chain sale or demolition
+    RetireAsset,
+
+    /// Replace the share mint, every `denominator` old shares become `numerator` new shares
+    ShareSplit { numerator: u32, denominator: u32 },
+
+    /// Change the governance settings of `AssetConfig` the authority can't touch, fields left
+    /// as `None` keep their current value
+    UpdateConfig {
+        timelock_delay: Option<i64>,
+        guardian: Option<Pubkey>,
+        supermajority_bps: Option<u16>,
+        proposal_threshold_bps: Option<u16>,
+        proposal_deposit: Option<u64>,
+        quorum_bps: Option<u16>,
+        tag_along_threshold_bps: Option<u16>,
+        tag_along_window: Option<i64>,
+    },
+
+    /// Change who manages the treasury, how much they can spend without a vote and how the
+    /// waterfall splits the treasury, fields left as `None` keep their current value
+    UpdateTreasuryConfig {
+        asset_manager: Option<Pubkey>,
+        manager_allowance: Option<u64>,
+        allowance_period: Option<i64>,
+        reserve_target: Option<u64>,
+        management_fee_bps: Option<u16>,
+        debt_service_amount: Option<u64>,
+        debt_service_recipient: Option<Pubkey>,
+    },
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct VoteState {
     pub voting_creator: Pubkey,
     pub vote_round: u64,
 
+    /// Short title, the full proposal lives at `metadata_uri`
     #[max_len(50)]
     pub description: String,
+
+    #[max_len(200)]
+    pub metadata_uri: String,
+
+    /// SHA-256 of the full proposal document so clients can detect off-chain edits
+    pub content_hash: [u8; 32],
     pub ft_mint: Pubkey,
     pub asset: Pubkey,
     pub yes_weight: u64,
     pub no_weight: u64,
+    pub voting_end_time: i64,
+
+    pub voting_mode: VotingMode,
+
+    /// Raw shares that voted, regardless of the voting mode
+    pub turnout: u64,
+
+    /// Raw shares that must vote for the round to pass, fixed at creation
+    pub quorum_weight: u64,
+
+    /// Lamports held by this account on top of rent, refunded to the creator on quorum
+    pub deposit: u64,
+
+    pub status: ProposalStatus,
+    pub action: Option<ProposalAction>,
     pub bump: u8,
 }
+
+impl VoteState {
+    /// Weight a holder of `raw_weight` shares votes with in this round
+    pub fn effective_weight(&self, raw_weight: u64) -> u64 {
+        match self.voting_mode {
+            VotingMode::TokenWeighted => raw_weight,
+            VotingMode::Quadratic => integer_sqrt(raw_weight),
+        }
+    }
+
+    pub fn add_vote(&mut self, choice: u8, raw_weight: u64, weight: u64) -> Result<()> {
+        let tally = if choice == 1 {
+            &mut self.yes_weight
+        } else {
+            &mut self.no_weight
+        };
+        *tally = tally.checked_add(weight).ok_or(ErrorCode::Overflow)?;
+        self.turnout = self
+            .turnout
+            .checked_add(raw_weight)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    pub fn remove_vote(&mut self, choice: u8, raw_weight: u64, weight: u64) -> Result<()> {
+        let tally = if choice == 1 {
+            &mut self.yes_weight
+        } else {
+            &mut self.no_weight
+        };
+        *tally = tally.checked_sub(weight).ok_or(ErrorCode::Overflow)?;
+        self.turnout = self
+            .turnout
+            .checked_sub(raw_weight)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    /// Last moment a passed action can be queued
+    pub fn queue_deadline(&self) -> i64 {
+        self.voting_end_time.saturating_add(PROPOSAL_QUEUE_WINDOW)
+    }
+
+    /// Whether the round is finalized and has no action left to queue or execute. A passed action
+    /// nobody queued within the queue window has expired.
+    pub fn is_settled(&self, now: i64) -> bool {
+        match self.status {
+            ProposalStatus::Active | ProposalStatus::Queued => false,
+            ProposalStatus::Passed => self.action.is_none() || now > self.queue_deadline(),
+            ProposalStatus::Rejected | ProposalStatus::Executed | ProposalStatus::Canceled => true,
+        }
+    }
+
+    /// Marks a queued proposal whose timelock expired as executed and returns the action to run
+    pub fn execute_action(&mut self, queued_action: &QueuedAction) -> Result<ProposalAction> {
+        require!(
+            self.status == ProposalStatus::Queued,
+            ErrorCode::ProposalNotQueued
+        );
+        require!(
+            Clock::get()?.unix_timestamp >= queued_action.eta,
+            ErrorCode::TimelockNotExpired
+        );
+        let action = self
+            .action
+            .clone()
+            .ok_or(ErrorCode::InvalidProposalAction)?;
+        self.status = ProposalStatus::Executed;
+        Ok(action)
+    }
+}
+
+// Largest integer whose square does not exceed `value`
+fn integer_sqrt(value: u64) -> u64 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteDelegation {
+    pub asset: Pubkey,
+
+    /// Holder whose voting power is delegated
+    pub delegator: Pubkey,
+
+    /// Wallet allowed to vote with the delegator's shares
+    pub delegate: Pubkey,
+
+    pub bump: u8,
+}
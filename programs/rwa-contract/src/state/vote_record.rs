@@ -8,8 +8,15 @@ pub struct VoteRecord {
 
     pub voter: Pubkey,
 
+    /// Wallet that cast the vote, the voter itself or its delegate
+    pub cast_by: Pubkey,
+
     pub choice: u8,
 
+    /// Shares held by the voter when the vote was cast
+    pub raw_weight: u64,
+
+    /// Weight counted in the tally after applying the round's voting mode
     pub weight: u64,
 
     pub bump: u8,
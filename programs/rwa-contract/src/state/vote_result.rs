@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ProposalStatus;
+
+/// Compact outcome of a vote round kept after its `VoteState` is closed
+#[account]
+#[derive(InitSpace)]
+pub struct VoteResult {
+    pub asset: Pubkey,
+    pub voting_creator: Pubkey,
+    pub vote_round: u64,
+    pub content_hash: [u8; 32],
+    pub yes_weight: u64,
+    pub no_weight: u64,
+    pub turnout: u64,
+    pub status: ProposalStatus,
+    pub bump: u8,
+}
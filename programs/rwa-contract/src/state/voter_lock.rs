@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Shares a holder escrowed to vote with, one lock per holder and share mint
+#[account]
+#[derive(InitSpace)]
+pub struct VoterLock {
+    pub ft_mint: Pubkey,
+    pub owner: Pubkey,
+
+    /// Shares held in the lock vault
+    pub amount: u64,
+
+    /// Shares stay in the vault until the last round this lock voted in has ended
+    pub locked_until: i64,
+
+    pub bump: u8,
+}
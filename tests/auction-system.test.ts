@@ -23,6 +23,16 @@ describe("Auction System Tests", () => {
   // USDC devnet mint
   const USDC_MINT = new PublicKey("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr");
 
+  // Every auction creator in these tests runs a single auction
+  const auctionId = new BN(0);
+
+  const waitUntil = async (unixTime: number) => {
+    const wait = unixTime * 1000 - Date.now() + 2000;
+    if (wait > 0) {
+      await new Promise((resolve) => setTimeout(resolve, wait));
+    }
+  };
+
   // Test accounts
   let assetKeypair: Keypair;
   let ftMintKeypair: Keypair;
@@ -100,7 +110,7 @@ describe("Auction System Tests", () => {
     );
 
     let create_ft = await program.methods
-      .createFungibleToken(6, 5) // Max supply is 255 (u8), using 5 tokens
+      .createFungibleToken(6, new BN(5), [], null)
       .accountsPartial({
         payer: auctionCreator.publicKey,
         mint: ftMintKeypair.publicKey,
@@ -123,6 +133,9 @@ describe("Auction System Tests", () => {
     const args = {
       name: "Auction Property NFT",
       uri: "https://example.com/auction-nft.json",
+      attributes: [],
+      // Short tag-along window so bidding opens during the test
+      tagAlongWindow: new BN(20),
     };
 
     let create_nft =  await program.methods
@@ -205,12 +218,20 @@ describe("Auction System Tests", () => {
 
     // Derive PDAs for auction using unique auction creator
     [auctionStatePda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("auction_state"), auctionCreator.publicKey.toBuffer()],
+      [
+        Buffer.from("auction_state"),
+        auctionCreator.publicKey.toBuffer(),
+        auctionId.toArrayLike(Buffer, "le", 8),
+      ],
       program.programId
     );
 
     [auctionVaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("auction_vault"), auctionCreator.publicKey.toBuffer()],
+      [
+        Buffer.from("auction_vault"),
+        auctionCreator.publicKey.toBuffer(),
+        auctionId.toArrayLike(Buffer, "le", 8),
+      ],
       program.programId
     );
 
@@ -255,7 +276,7 @@ describe("Auction System Tests", () => {
       const auctionEndTime = new BN(Math.floor(Date.now() / 1000) + 3600); // 1 hour from now
 
       const tx = await program.methods
-        .createAuction(auctionAmount, auctionEndTime)
+        .createAuction(auctionId, auctionAmount, auctionEndTime, null)
         .accountsPartial({
           payer: auctionCreator.publicKey,
           ftMint: ftMintKeypair.publicKey,
@@ -281,6 +302,10 @@ describe("Auction System Tests", () => {
       assert.isTrue(auctionState.isActive);
       assert.equal(auctionState.highestBid.toNumber(), 0);
       assert.equal(auctionState.auctionEndTime.toString(), auctionEndTime.toString());
+      // 2 of 5 shares is above the 20% tag-along threshold, bidding opens after the window
+      assert.isTrue(auctionState.joinEndTime.toNumber() > 0);
+      assert.isTrue(auctionState.joinEndTime.lt(auctionEndTime));
+      assert.equal(auctionState.totalAmount.toString(), auctionAmount.toString());
 
       // Verify tokens were transferred to vault
       const vaultAccount = await getAccount(
@@ -316,18 +341,26 @@ describe("Auction System Tests", () => {
       );
 
       const [newAuctionState] = PublicKey.findProgramAddressSync(
-        [Buffer.from("auction_state"), newAuctionCreator.publicKey.toBuffer()],
+        [
+          Buffer.from("auction_state"),
+          newAuctionCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
         program.programId
       );
 
       const [newAuctionVault] = PublicKey.findProgramAddressSync(
-        [Buffer.from("auction_vault"), newAuctionCreator.publicKey.toBuffer()],
+        [
+          Buffer.from("auction_vault"),
+          newAuctionCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
         program.programId
       );
 
       try {
         await program.methods
-          .createAuction(excessiveAmount, auctionEndTime)
+          .createAuction(auctionId, excessiveAmount, auctionEndTime, null)
           .accountsPartial({
             payer: newAuctionCreator.publicKey,
             ftMint: ftMintKeypair.publicKey,
@@ -349,7 +382,114 @@ describe("Auction System Tests", () => {
     });
   });
 
+  describe("Tag-Along", () => {
+    it("Lets another holder join a large auction", async () => {
+      const tagAlongSeller = Keypair.generate();
+      const fundTx = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: tagAlongSeller.publicKey,
+          lamports: 0.01 * anchor.web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(fundTx);
+
+      const sellerTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        ftMintKeypair.publicKey,
+        tagAlongSeller.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        auctionCreatorTokenAccount,
+        sellerTokenAccount.address,
+        auctionCreator,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+
+      const [contributionPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_contribution"),
+          auctionStatePda.toBuffer(),
+          tagAlongSeller.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .joinAuction(new BN(1_000_000))
+        .accountsPartial({
+          seller: tagAlongSeller.publicKey,
+          auctionCreator: auctionCreator.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          auctionState: auctionStatePda,
+          sellerTokenAccount: sellerTokenAccount.address,
+          auctionVault: auctionVaultPda,
+          contribution: contributionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([tagAlongSeller])
+        .rpc();
+
+      const auctionState = await program.account.auctionState.fetch(auctionStatePda);
+      assert.equal(auctionState.totalAmount.toString(), "3000000");
+      assert.equal(auctionState.contributorCount, 1);
+
+      const contribution = await program.account.auctionContribution.fetch(contributionPda);
+      assert.equal(contribution.seller.toString(), tagAlongSeller.publicKey.toString());
+      assert.equal(contribution.amount.toString(), "1000000");
+      assert.isFalse(contribution.paid);
+
+      const vaultAccount = await getAccount(
+        provider.connection,
+        auctionVaultPda,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      assert.equal(vaultAccount.amount.toString(), "3000000");
+    });
+
+    it("Rejects bids while the tag-along window is open", async () => {
+      try {
+        await program.methods
+          .placeBid(new BN(500_000))
+          .accountsPartial({
+            bidder: bidder1.publicKey,
+            auctionCreator: auctionCreator.publicKey,
+            asset: assetKeypair.publicKey,
+            usdcMint: usdcMint,
+            bidderUsdcAccount: bidder1UsdcAccount,
+            auctionState: auctionStatePda,
+            assetState: assetStatePda,
+            bidsVault: bidsVaultPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([bidder1])
+          .rpc();
+
+        assert.fail("Should have failed before bidding opens");
+      } catch (error) {
+        assert.include(error.message, "BiddingNotOpen");
+      }
+    });
+  });
+
   describe("Bidding", () => {
+    before(async () => {
+      const auctionState = await program.account.auctionState.fetch(auctionStatePda);
+      console.log("Waiting for the tag-along window to close...");
+      await waitUntil(auctionState.joinEndTime.toNumber());
+    });
+
     it("Allows first bid on active auction", async () => {
         const bidAmount = new BN(500_000); // 0.5 USDC
 
@@ -514,6 +654,7 @@ describe("Auction System Tests", () => {
             auctionCreatorUsdcAccount: auctionCreatorUsdcAccount,
             highestBidderAssetAccount: highestBidderAssetAccount,
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
           })
           .rpc();
 
@@ -555,9 +696,9 @@ describe("Auction System Tests", () => {
         TOKEN_PROGRAM_ID
       );
 
-      // Create FT
+      // Create FT, 1 of 10 shares stays below the tag-along threshold so bidding opens at once
       await program.methods
-        .createFungibleToken(6, 3)
+        .createFungibleToken(6, new BN(10), [], null)
         .accountsPartial({
           payer: shortAuctionCreator.publicKey,
           mint: shortFtMint.publicKey,
@@ -574,6 +715,7 @@ describe("Auction System Tests", () => {
         .createNonFungibleToken({
           name: "Short Auction NFT",
           uri: "https://example.com/short-auction.json",
+          attributes: [],
         })
         .accountsPartial({
           payer: shortAuctionCreator.publicKey,
@@ -585,12 +727,20 @@ describe("Auction System Tests", () => {
 
       // Derive auction PDAs
       const [shortAuctionState] = PublicKey.findProgramAddressSync(
-        [Buffer.from("auction_state"), shortAuctionCreator.publicKey.toBuffer()],
+        [
+          Buffer.from("auction_state"),
+          shortAuctionCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
         program.programId
       );
 
       const [shortAuctionVault] = PublicKey.findProgramAddressSync(
-        [Buffer.from("auction_vault"), shortAuctionCreator.publicKey.toBuffer()],
+        [
+          Buffer.from("auction_vault"),
+          shortAuctionCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
         program.programId
       );
 
@@ -606,7 +756,7 @@ describe("Auction System Tests", () => {
       const shortAmount = new BN(1_000_000);
 
       await program.methods
-        .createAuction(shortAmount, shortEndTime)
+        .createAuction(auctionId, shortAmount, shortEndTime, null)
         .accountsPartial({
           payer: shortAuctionCreator.publicKey,
           ftMint: shortFtMint.publicKey,
@@ -681,6 +831,7 @@ describe("Auction System Tests", () => {
             highestBidderAssetAccount: bidder1AssetAccount,
             systemProgram: SystemProgram.programId,
             tokenProgram: TOKEN_PROGRAM_ID,
+            shareTokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           })
           .rpc();
@@ -715,4 +866,452 @@ describe("Auction System Tests", () => {
       assert.equal(bidderAssetBalance.amount.toString(), shortAmount.toString());
     });
   });
+
+  describe("Right of First Refusal", () => {
+    const rofrCreator = Keypair.generate();
+    const rofrAsset = Keypair.generate();
+    const rofrFtMint = Keypair.generate();
+    const askingPrice = new BN(1_000_000); // 1 USDC
+    let rofrAuctionState: PublicKey;
+    let rofrAuctionVault: PublicKey;
+    let rofrAssetState: PublicKey;
+    let rofrBidsVault: PublicKey;
+
+    before(async () => {
+      const fundTx = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: rofrCreator.publicKey,
+          lamports: 0.1 * anchor.web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(fundTx);
+
+      const creatorTokenAccount = getAssociatedTokenAddressSync(
+        rofrFtMint.publicKey,
+        rofrCreator.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+
+      await program.methods
+        .createFungibleToken(6, new BN(5), [], null)
+        .accountsPartial({
+          payer: rofrCreator.publicKey,
+          mint: rofrFtMint.publicKey,
+          tokenAccount: creatorTokenAccount,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        })
+        .signers([rofrFtMint, rofrCreator])
+        .rpc();
+
+      await program.methods
+        .createNonFungibleToken({
+          name: "ROFR Auction NFT",
+          uri: "https://example.com/rofr-auction.json",
+          attributes: [],
+        })
+        .accountsPartial({
+          payer: rofrCreator.publicKey,
+          asset: rofrAsset.publicKey,
+          ftMint: rofrFtMint.publicKey,
+        })
+        .signers([rofrAsset, rofrCreator])
+        .rpc();
+
+      // bidder2 becomes an existing holder
+      const holderTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        rofrFtMint.publicKey,
+        bidder2.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        creatorTokenAccount,
+        holderTokenAccount.address,
+        rofrCreator,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+
+      [rofrAssetState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), rofrAsset.publicKey.toBuffer()],
+        program.programId
+      );
+      [rofrAuctionState] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_state"),
+          rofrCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [rofrAuctionVault] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_vault"),
+          rofrCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      rofrBidsVault = getAssociatedTokenAddressSync(
+        usdcMint,
+        rofrAuctionState,
+        true,
+        TOKEN_PROGRAM_ID
+      );
+
+      // 1 of 5 shares stays at the tag-along threshold, so no one can join
+      await program.methods
+        .createAuction(
+          auctionId,
+          new BN(1_000_000),
+          new BN(Math.floor(Date.now() / 1000) + 3600),
+          { duration: new BN(600), askingPrice }
+        )
+        .accountsPartial({
+          payer: rofrCreator.publicKey,
+          ftMint: rofrFtMint.publicKey,
+          usdcMint: usdcMint,
+          asset: rofrAsset.publicKey,
+          assetState: rofrAssetState,
+          tokenAccount: creatorTokenAccount,
+          auctionState: rofrAuctionState,
+          auctionVault: rofrAuctionVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([rofrCreator])
+        .rpc();
+    });
+
+    it("Stores the ROFR window and asking price", async () => {
+      const auctionState = await program.account.auctionState.fetch(rofrAuctionState);
+      assert.isTrue(auctionState.rofrEndTime.toNumber() > 0);
+      assert.equal(auctionState.askingPrice.toString(), askingPrice.toString());
+      assert.equal(auctionState.joinEndTime.toNumber(), 0);
+    });
+
+    it("Rejects bids from non-holders during the ROFR window", async () => {
+      try {
+        await program.methods
+          .placeBid(new BN(500_000))
+          .accountsPartial({
+            bidder: bidder1.publicKey,
+            auctionCreator: rofrCreator.publicKey,
+            asset: rofrAsset.publicKey,
+            usdcMint: usdcMint,
+            bidderUsdcAccount: bidder1UsdcAccount,
+            auctionState: rofrAuctionState,
+            assetState: rofrAssetState,
+            bidsVault: rofrBidsVault,
+            bidderShareAccount: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([bidder1])
+          .rpc();
+
+        assert.fail("Should have failed for a non-holder");
+      } catch (error) {
+        assert.include(error.message, "NotTokenBalance");
+      }
+    });
+
+    it("Lets a holder buy the block at the asking price", async () => {
+      const holderShareAccount = getAssociatedTokenAddressSync(
+        rofrFtMint.publicKey,
+        bidder2.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+      const creatorUsdcAccount = getAssociatedTokenAddressSync(
+        usdcMint,
+        rofrCreator.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+
+      await program.methods
+        .exerciseRofr()
+        .accountsPartial({
+          buyer: bidder2.publicKey,
+          auctionCreator: rofrCreator.publicKey,
+          ftMint: rofrFtMint.publicKey,
+          usdcMint: usdcMint,
+          auctionState: rofrAuctionState,
+          assetState: rofrAssetState,
+          auctionVault: rofrAuctionVault,
+          bidsVault: rofrBidsVault,
+          buyerShareAccount: holderShareAccount,
+          buyerUsdcAccount: bidder2UsdcAccount,
+          auctionCreatorUsdcAccount: creatorUsdcAccount,
+          highestBidderUsdcAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([bidder2])
+        .rpc();
+
+      const auctionState = await program.account.auctionState.fetch(rofrAuctionState);
+      assert.isFalse(auctionState.isActive);
+      assert.equal(auctionState.highestBidder.toString(), bidder2.publicKey.toString());
+
+      const holderShares = await getAccount(
+        provider.connection,
+        holderShareAccount,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      assert.equal(holderShares.amount.toString(), "2000000");
+
+      const creatorUsdc = await getAccount(
+        provider.connection,
+        creatorUsdcAccount,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      assert.equal(creatorUsdc.amount.toString(), askingPrice.toString());
+    });
+  });
+
+  describe("Unsold Auction", () => {
+    const unsoldCreator = Keypair.generate();
+    const unsoldSeller = Keypair.generate();
+    const unsoldAsset = Keypair.generate();
+    const unsoldFtMint = Keypair.generate();
+    let creatorTokenAccount: PublicKey;
+    let sellerTokenAccount: PublicKey;
+    let unsoldAuctionState: PublicKey;
+    let unsoldAuctionVault: PublicKey;
+    let contributionPda: PublicKey;
+
+    const join = (amount: number) =>
+      program.methods
+        .joinAuction(new BN(amount))
+        .accountsPartial({
+          seller: unsoldSeller.publicKey,
+          auctionCreator: unsoldCreator.publicKey,
+          ftMint: unsoldFtMint.publicKey,
+          auctionState: unsoldAuctionState,
+          sellerTokenAccount,
+          auctionVault: unsoldAuctionVault,
+          contribution: contributionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([unsoldSeller])
+        .rpc();
+
+    const reclaim = () =>
+      program.methods
+        .reclaimUnsoldShares()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          auctionCreator: unsoldCreator.publicKey,
+          ftMint: unsoldFtMint.publicKey,
+          auctionState: unsoldAuctionState,
+          assetState: PublicKey.findProgramAddressSync(
+            [Buffer.from("asset_state"), unsoldAsset.publicKey.toBuffer()],
+            program.programId
+          )[0],
+          auctionVault: unsoldAuctionVault,
+          creatorTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    before(async () => {
+      const fundTx = new anchor.web3.Transaction()
+        .add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: wallet.publicKey,
+            toPubkey: unsoldCreator.publicKey,
+            lamports: 0.1 * anchor.web3.LAMPORTS_PER_SOL,
+          })
+        )
+        .add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: wallet.publicKey,
+            toPubkey: unsoldSeller.publicKey,
+            lamports: 0.01 * anchor.web3.LAMPORTS_PER_SOL,
+          })
+        );
+      await provider.sendAndConfirm(fundTx);
+
+      creatorTokenAccount = getAssociatedTokenAddressSync(
+        unsoldFtMint.publicKey,
+        unsoldCreator.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+
+      await program.methods
+        .createFungibleToken(6, new BN(10), [], null)
+        .accountsPartial({
+          payer: unsoldCreator.publicKey,
+          mint: unsoldFtMint.publicKey,
+          tokenAccount: creatorTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([unsoldFtMint, unsoldCreator])
+        .rpc();
+
+      await program.methods
+        .createNonFungibleToken({
+          name: "Unsold Auction NFT",
+          uri: "https://example.com/unsold-auction.json",
+          attributes: [],
+          tagAlongWindow: new BN(8),
+        })
+        .accountsPartial({
+          payer: unsoldCreator.publicKey,
+          asset: unsoldAsset.publicKey,
+          ftMint: unsoldFtMint.publicKey,
+        })
+        .signers([unsoldAsset, unsoldCreator])
+        .rpc();
+
+      sellerTokenAccount = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          wallet.payer,
+          unsoldFtMint.publicKey,
+          unsoldSeller.publicKey,
+          false,
+          undefined,
+          undefined,
+          TOKEN_PROGRAM_ID
+        )
+      ).address;
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        creatorTokenAccount,
+        sellerTokenAccount,
+        unsoldCreator,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+
+      [unsoldAuctionState] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_state"),
+          unsoldCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [unsoldAuctionVault] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_vault"),
+          unsoldCreator.publicKey.toBuffer(),
+          auctionId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [contributionPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("auction_contribution"),
+          unsoldAuctionState.toBuffer(),
+          unsoldSeller.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      // 5 of 10 shares opens an 8 second tag-along window
+      await program.methods
+        .createAuction(
+          auctionId,
+          new BN(5_000_000),
+          new BN(Math.floor(Date.now() / 1000) + 14),
+          null
+        )
+        .accountsPartial({
+          payer: unsoldCreator.publicKey,
+          ftMint: unsoldFtMint.publicKey,
+          usdcMint: usdcMint,
+          asset: unsoldAsset.publicKey,
+          tokenAccount: creatorTokenAccount,
+          auctionState: unsoldAuctionState,
+          auctionVault: unsoldAuctionVault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([unsoldCreator])
+        .rpc();
+    });
+
+    it("Rejects tag-along contributions below the minimum", async () => {
+      try {
+        // 0.01 of 10 shares is below the 1% minimum
+        await join(10_000);
+        assert.fail("Should have failed below the minimum contribution");
+      } catch (error) {
+        assert.include(error.message, "JoinAmountTooSmall");
+      }
+    });
+
+    it("Returns every seller's shares after an auction without bids", async () => {
+      await join(1_000_000);
+
+      const auctionState = await program.account.auctionState.fetch(unsoldAuctionState);
+      console.log("Waiting for the auction to end...");
+      await waitUntil(auctionState.auctionEndTime.toNumber());
+
+      // The tag-along seller has to be refunded first
+      try {
+        await reclaim();
+        assert.fail("Should have failed with a contribution outstanding");
+      } catch (error) {
+        assert.include(error.message, "ContributionsOutstanding");
+      }
+
+      // Anyone can refund the tag-along seller
+      await program.methods
+        .refundContribution()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          seller: unsoldSeller.publicKey,
+          auctionCreator: unsoldCreator.publicKey,
+          ftMint: unsoldFtMint.publicKey,
+          auctionState: unsoldAuctionState,
+          auctionVault: unsoldAuctionVault,
+          contribution: contributionPda,
+          sellerTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const sellerShares = await getAccount(
+        provider.connection,
+        sellerTokenAccount,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      assert.equal(sellerShares.amount.toString(), "1000000");
+      assert.isNull(await provider.connection.getAccountInfo(contributionPda));
+
+      await reclaim();
+
+      const creatorShares = await getAccount(
+        provider.connection,
+        creatorTokenAccount,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      assert.equal(creatorShares.amount.toString(), "9000000");
+
+      const closedAuction = await program.account.auctionState.fetch(unsoldAuctionState);
+      assert.isFalse(closedAuction.isActive);
+    });
+  });
 });
@@ -0,0 +1,298 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { RwaContract } from "../target/types/rwa_contract";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+describe("Buyout System Tests", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.RwaContract as Program<RwaContract>;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  // USDC devnet mint
+  const USDC_MINT = new PublicKey("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr");
+  // 0.1 USDC per whole share, 10 shares in circulation
+  const PRICE_PER_SHARE = new BN(100_000);
+  const SUPPLY = 10_000_000;
+
+  const holderAccount = (mint: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+  const voterLockPda = (mint: PublicKey, owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("voter_lock"), mint.toBuffer(), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  const lockShares = (mint: PublicKey, amount: number) =>
+    program.methods
+      .lockShares(new BN(amount))
+      .accountsPartial({
+        owner: wallet.publicKey,
+        ftMint: mint,
+        ownerTokenAccount: holderAccount(mint),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  const unlockShares = (mint: PublicKey, amount: number) =>
+    program.methods
+      .unlockShares(new BN(amount))
+      .accountsPartial({
+        owner: wallet.publicKey,
+        ftMint: mint,
+        ownerTokenAccount: holderAccount(mint),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  // A property without timelock whose single holder owns all 10 shares
+  const createProperty = async (name: string) => {
+    const asset = Keypair.generate();
+    const mint = Keypair.generate();
+
+    await program.methods
+      .createFungibleToken(6, new BN(10), [], null)
+      .accountsPartial({
+        payer: wallet.publicKey,
+        mint: mint.publicKey,
+        tokenAccount: holderAccount(mint.publicKey),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    await program.methods
+      .createNonFungibleToken({
+        name,
+        uri: "https://example.com/buyout-nft.json",
+        attributes: [],
+        timelockDelay: new BN(0),
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset: asset.publicKey,
+        ftMint: mint.publicKey,
+      })
+      .signers([asset])
+      .rpc();
+
+    return { asset: asset.publicKey, mint: mint.publicKey };
+  };
+
+  // Runs a Buyout round to the queued state with `lockedAmount` shares voting YES
+  const passBuyout = async (asset: PublicKey, mint: PublicKey, lockedAmount: number) => {
+    const voteRound = new BN(0);
+    const [voteState] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote_state"),
+        asset.toBuffer(),
+        wallet.publicKey.toBuffer(),
+        voteRound.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [queuedAction] = PublicKey.findProgramAddressSync(
+      [Buffer.from("queued_action"), voteState.toBuffer()],
+      program.programId
+    );
+    const description = "Sell the property at 0.1 USDC per share";
+
+    await lockShares(mint, lockedAmount);
+
+    await program.methods
+      .createVoteRound({
+        description,
+        metadataUri: "https://example.com/proposals/buyout.json",
+        contentHash: Array.from(createHash("sha256").update(description).digest()),
+        votingEndTime: new BN(Math.floor(Date.now() / 1000) + 10),
+        votingMode: { tokenWeighted: {} },
+        action: { buyout: { buyer: wallet.publicKey, pricePerShare: PRICE_PER_SHARE } },
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        tokenAccount: holderAccount(mint),
+        voteState,
+      })
+      .rpc();
+
+    await program.methods
+      .vote(wallet.publicKey, voteRound, 1)
+      .accountsPartial({
+        voter: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        voteState,
+        voterLock: voterLockPda(mint, wallet.publicKey),
+      })
+      .rpc();
+
+    console.log("Waiting 12 seconds for voting to end...");
+    await new Promise((resolve) => setTimeout(resolve, 12000));
+
+    await program.methods
+      .finalizeVoteRound()
+      .accountsPartial({ asset, votingCreator: wallet.publicKey, voteState })
+      .rpc();
+
+    await program.methods
+      .queueProposal()
+      .accountsPartial({ payer: wallet.publicKey, asset, voteState, queuedAction })
+      .rpc();
+
+    await unlockShares(mint, lockedAmount);
+
+    return { voteState, queuedAction };
+  };
+
+  const fundBuyout = (
+    asset: PublicKey,
+    mint: PublicKey,
+    voteState: PublicKey,
+    queuedAction: PublicKey,
+    buyerUsdcAccount: PublicKey
+  ) =>
+    program.methods
+      .fundBuyout()
+      .accountsPartial({
+        buyer: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        usdcMint: USDC_MINT,
+        voteState,
+        queuedAction,
+        buyerUsdcAccount,
+        usdcTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+  const completeBuyout = (asset: PublicKey, buyoutState: PublicKey) =>
+    program.methods
+      .completeBuyout()
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset,
+        buyoutState,
+        buyer: wallet.publicKey,
+        collection: null,
+      })
+      .rpc();
+
+  // mpl-core assets store the owner right after the account key byte
+  const nftOwner = async (asset: PublicKey) =>
+    new PublicKey((await provider.connection.getAccountInfo(asset)).data.subarray(1, 33));
+
+  let walletUsdcAccount: PublicKey;
+
+  before(async () => {
+    walletUsdcAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        USDC_MINT,
+        wallet.publicKey
+      )
+    ).address;
+  });
+
+  it("Rejects a buyout passed without the supermajority of the share supply", async () => {
+    const { asset, mint } = await createProperty("Buyout Minority Property");
+    // Half of the supply voted, below the 75% default supermajority
+    const { voteState, queuedAction } = await passBuyout(asset, mint, SUPPLY / 2);
+
+    try {
+      await fundBuyout(asset, mint, voteState, queuedAction, walletUsdcAccount);
+      assert.fail("Should have failed without the supermajority");
+    } catch (error) {
+      assert.include(error.toString(), "SupermajorityNotReached");
+    }
+  });
+
+  it("Funds a buyout, pays holders on claim and hands over the NFT once every share is sold", async () => {
+    const { asset, mint } = await createProperty("Buyout Property");
+    const { voteState, queuedAction } = await passBuyout(asset, mint, (SUPPLY * 9) / 10);
+
+    const usdcBefore = (await getAccount(provider.connection, walletUsdcAccount)).amount;
+
+    await fundBuyout(asset, mint, voteState, queuedAction, walletUsdcAccount);
+
+    const [buyoutState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("buyout_state"), asset.toBuffer(), voteState.toBuffer()],
+      program.programId
+    );
+    const buyout = await program.account.buyoutState.fetch(buyoutState);
+    assert.equal(buyout.remainingShares.toNumber(), SUPPLY);
+
+    // 10 shares at 0.1 USDC leave the buyer's account
+    const usdcFunded = (await getAccount(provider.connection, walletUsdcAccount)).amount;
+    assert.equal(Number(usdcBefore) - Number(usdcFunded), 1_000_000);
+
+    // The NFT stays with the owner PDA while shares are outstanding
+    const [ownerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_onwer"), asset.toBuffer()],
+      program.programId
+    );
+    assert.equal((await nftOwner(asset)).toString(), ownerPda.toString());
+
+    try {
+      await completeBuyout(asset, buyoutState);
+      assert.fail("Should have failed while shares are outstanding");
+    } catch (error) {
+      assert.include(error.toString(), "BuyoutSharesOutstanding");
+    }
+
+    const [assetStatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("asset_state"), asset.toBuffer()],
+      program.programId
+    );
+    let assetState = await program.account.assetState.fetch(assetStatePda);
+    assert.deepEqual(assetState.status, { buyoutPending: {} });
+
+    await program.methods
+      .claimBuyout()
+      .accountsPartial({
+        holder: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        holderTokenAccount: holderAccount(mint),
+        buyoutState,
+        usdcMint: USDC_MINT,
+        holderUsdcAccount: walletUsdcAccount,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        usdcTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    const usdcClaimed = (await getAccount(provider.connection, walletUsdcAccount)).amount;
+    assert.equal(usdcClaimed.toString(), usdcBefore.toString());
+
+    const shares = await getAccount(
+      provider.connection,
+      holderAccount(mint),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(shares.amount.toString(), "0");
+
+    assetState = await program.account.assetState.fetch(assetStatePda);
+    assert.deepEqual(assetState.status, { buyoutPending: {} });
+
+    await completeBuyout(asset, buyoutState);
+
+    assert.equal((await nftOwner(asset)).toString(), wallet.publicKey.toString());
+    assetState = await program.account.assetState.fetch(assetStatePda);
+    assert.deepEqual(assetState.status, { redeemed: {} });
+  });
+});
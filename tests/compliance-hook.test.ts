@@ -0,0 +1,340 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { RwaContract } from "../target/types/rwa_contract";
+import { ComplianceHook } from "../target/types/compliance_hook";
+import {
+  PublicKey,
+  Keypair,
+  SystemProgram,
+  Transaction,
+  sendAndConfirmTransaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  addExtraAccountMetasForExecute,
+  createAccount,
+  createAssociatedTokenAccountInstruction,
+  createTransferCheckedInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMint,
+  getTokenMetadata,
+  getTransferHook,
+} from "@solana/spl-token";
+import { MPL_CORE_PROGRAM_ID } from "@metaplex-foundation/mpl-core";
+import { assert } from "chai";
+
+describe("Compliance Hook", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.RwaContract as Program<RwaContract>;
+  const hookProgram = anchor.workspace.ComplianceHook as Program<ComplianceHook>;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  const mintKeypair = Keypair.generate();
+  const assetKeypair = Keypair.generate();
+  const registeredInvestor = Keypair.generate();
+  const unregisteredWallet = Keypair.generate();
+
+  const [extraAccountMetaList] = PublicKey.findProgramAddressSync(
+    [Buffer.from("extra-account-metas"), mintKeypair.publicKey.toBuffer()],
+    hookProgram.programId
+  );
+  const [complianceConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("compliance_config"), mintKeypair.publicKey.toBuffer()],
+    hookProgram.programId
+  );
+  const [complianceAuthority] = PublicKey.findProgramAddressSync(
+    [Buffer.from("compliance_authority"), assetKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const investorEntryOf = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("investor_entry"),
+        mintKeypair.publicKey.toBuffer(),
+        owner.toBuffer(),
+      ],
+      hookProgram.programId
+    )[0];
+
+  // Accounts of the compliance registry of the asset, the entry is the one of `owner`
+  const registryAccounts = (owner: PublicKey) => ({
+    payer: wallet.publicKey,
+    asset: assetKeypair.publicKey,
+    ftMint: mintKeypair.publicKey,
+    complianceConfig,
+    investorEntry: investorEntryOf(owner),
+  });
+
+  const tokenAccountOf = (owner: PublicKey) =>
+    getAssociatedTokenAddressSync(
+      mintKeypair.publicKey,
+      owner,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+  // Transfer instruction with the hook's extra accounts resolved
+  const transferInstruction = async (destination: PublicKey, amount: number) => {
+    const transferIx = createTransferCheckedInstruction(
+      tokenAccountOf(wallet.publicKey),
+      mintKeypair.publicKey,
+      tokenAccountOf(destination),
+      wallet.publicKey,
+      amount,
+      6,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await addExtraAccountMetasForExecute(
+      provider.connection,
+      transferIx,
+      hookProgram.programId,
+      tokenAccountOf(wallet.publicKey),
+      mintKeypair.publicKey,
+      tokenAccountOf(destination),
+      wallet.publicKey,
+      amount,
+      "confirmed"
+    );
+
+    return transferIx;
+  };
+
+  // Creates the destination token account and transfers shares into it
+  const transferShares = async (destination: PublicKey, amount: number) => {
+    const transaction = new Transaction().add(
+      createAssociatedTokenAccountInstruction(
+        wallet.publicKey,
+        tokenAccountOf(destination),
+        destination,
+        mintKeypair.publicKey,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      await transferInstruction(destination, amount)
+    );
+
+    return sendAndConfirmTransaction(provider.connection, transaction, [wallet.payer]);
+  };
+
+  before(async () => {
+    await program.methods
+      .createCompliantFungibleToken(6, new BN(100), [], assetKeypair.publicKey, {
+        name: "Compliant Property",
+        symbol: "CPROP",
+        uri: "https://example.com/compliant-property.json",
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        mint: mintKeypair.publicKey,
+        extraAccountMetaList,
+        complianceConfig,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        complianceHookProgram: hookProgram.programId,
+      })
+      .signers([mintKeypair])
+      .rpc();
+
+    await program.methods
+      .createNonFungibleToken({
+        name: "Compliant Property",
+        uri: "https://example.com/compliant-property.json",
+        attributes: [],
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset: assetKeypair.publicKey,
+        ftMint: mintKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+      })
+      .signers([wallet.payer, assetKeypair])
+      .rpc();
+
+    // The tokenizer is the asset's compliance officer
+    await program.methods
+      .registerInvestor(registeredInvestor.publicKey)
+      .accountsPartial(registryAccounts(registeredInvestor.publicKey))
+      .rpc();
+  });
+
+  it("Hands the registry to the asset's compliance authority", async () => {
+    const config = await hookProgram.account.complianceConfig.fetch(complianceConfig);
+    assert.equal(config.authority.toString(), complianceAuthority.toString());
+
+    // Nobody can point the mint at another hook program
+    const mintInfo = await getMint(
+      provider.connection,
+      mintKeypair.publicKey,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.isTrue(getTransferHook(mintInfo).authority.equals(PublicKey.default));
+  });
+
+  it("Writes the property metadata into the share mint", async () => {
+    const metadata = await getTokenMetadata(
+      provider.connection,
+      mintKeypair.publicKey,
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(metadata.name, "Compliant Property");
+    assert.equal(metadata.symbol, "CPROP");
+    assert.deepEqual(metadata.additionalMetadata, [
+      ["property_asset", assetKeypair.publicKey.toString()],
+    ]);
+  });
+
+  it("Rejects registrations from anyone but the compliance officer", async () => {
+    const outsider = Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(outsider.publicKey, 1_000_000_000)
+    );
+
+    try {
+      await program.methods
+        .registerInvestor(outsider.publicKey)
+        .accountsPartial({
+          ...registryAccounts(outsider.publicKey),
+          payer: outsider.publicKey,
+        })
+        .signers([outsider])
+        .rpc();
+      assert.fail("Registration by an outsider should have failed");
+    } catch (error) {
+      assert.include(error.toString(), "UnauthorizedComplianceAction");
+    }
+  });
+
+  it("Allows transfers to registered investors", async () => {
+    await transferShares(registeredInvestor.publicKey, 5_000_000);
+
+    const investorAccount = await getAccount(
+      provider.connection,
+      tokenAccountOf(registeredInvestor.publicKey),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    assert.equal(investorAccount.amount.toString(), "5000000");
+  });
+
+  it("Blocks transfers to wallets without a registry entry", async () => {
+    try {
+      await transferShares(unregisteredWallet.publicKey, 1_000_000);
+      assert.fail("Transfer to an unregistered wallet should have failed");
+    } catch (error) {
+      assert.include(error.toString(), "InvestorNotRegistered");
+    }
+  });
+
+  it("Blocks transfers into accounts whose owner can be changed", async () => {
+    // A token account at a fresh keypair lacks the ImmutableOwner extension ATAs get
+    const mutableAccount = await createAccount(
+      provider.connection,
+      wallet.payer,
+      mintKeypair.publicKey,
+      registeredInvestor.publicKey,
+      Keypair.generate(),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const transferIx = createTransferCheckedInstruction(
+      tokenAccountOf(wallet.publicKey),
+      mintKeypair.publicKey,
+      mutableAccount,
+      wallet.publicKey,
+      1_000_000,
+      6,
+      [],
+      TOKEN_2022_PROGRAM_ID
+    );
+    await addExtraAccountMetasForExecute(
+      provider.connection,
+      transferIx,
+      hookProgram.programId,
+      tokenAccountOf(wallet.publicKey),
+      mintKeypair.publicKey,
+      mutableAccount,
+      wallet.publicKey,
+      1_000_000,
+      "confirmed"
+    );
+
+    try {
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(transferIx),
+        [wallet.payer]
+      );
+      assert.fail("Transfer to an account with a mutable owner should have failed");
+    } catch (error) {
+      assert.include(error.toString(), "MutableDestinationOwner");
+    }
+  });
+
+  it("Moves shares into program vaults once they are registered", async () => {
+    const [voterEscrow] = PublicKey.findProgramAddressSync(
+      [Buffer.from("voter_escrow"), mintKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    // Anyone can register the program's own share accounts
+    await program.methods
+      .registerProgramAccount({ voterEscrow: {} })
+      .accountsPartial(registryAccounts(voterEscrow))
+      .rpc();
+
+    // The program resolves the hook's extra accounts from the remaining accounts
+    await program.methods
+      .lockShares(new BN(1_000_000))
+      .accountsPartial({
+        owner: wallet.publicKey,
+        ftMint: mintKeypair.publicKey,
+        ownerTokenAccount: tokenAccountOf(wallet.publicKey),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts([
+        { pubkey: investorEntryOf(voterEscrow), isSigner: false, isWritable: false },
+        { pubkey: hookProgram.programId, isSigner: false, isWritable: false },
+        { pubkey: extraAccountMetaList, isSigner: false, isWritable: false },
+      ])
+      .rpc();
+
+    const [voterLock] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("voter_lock"),
+        mintKeypair.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const lock = await program.account.voterLock.fetch(voterLock);
+    assert.equal(lock.amount.toString(), "1000000");
+  });
+
+  it("Blocks transfers once an investor is removed", async () => {
+    await program.methods
+      .unregisterInvestor()
+      .accountsPartial(registryAccounts(registeredInvestor.publicKey))
+      .rpc();
+
+    try {
+      await sendAndConfirmTransaction(
+        provider.connection,
+        new Transaction().add(
+          await transferInstruction(registeredInvestor.publicKey, 1_000_000)
+        ),
+        [wallet.payer]
+      );
+      assert.fail("Transfer to a removed investor should have failed");
+    } catch (error) {
+      assert.include(error.toString(), "InvestorNotRegistered");
+    }
+  });
+});
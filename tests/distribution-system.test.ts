@@ -0,0 +1,400 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { RwaContract } from "../target/types/rwa_contract";
+import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+  getOrCreateAssociatedTokenAccount,
+  transfer,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+describe("Distribution System Tests", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.RwaContract as Program<RwaContract>;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  // USDC devnet mint
+  const USDC_MINT = new PublicKey("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr");
+
+  const sha256 = (...parts: Buffer[]) =>
+    createHash("sha256").update(Buffer.concat(parts)).digest();
+
+  // Same leaf layout for share snapshots and merkle distribution allocations
+  const leafHash = (index: number, holder: PublicKey, amount: BN) => {
+    const indexBytes = Buffer.alloc(4);
+    indexBytes.writeUInt32LE(index);
+    return sha256(
+      Buffer.from([0]),
+      indexBytes,
+      holder.toBuffer(),
+      amount.toArrayLike(Buffer, "le", 8)
+    );
+  };
+
+  const nodeHash = (a: Buffer, b: Buffer) =>
+    Buffer.compare(a, b) <= 0
+      ? sha256(Buffer.from([1]), a, b)
+      : sha256(Buffer.from([1]), b, a);
+
+  const walletUsdcAccount = getAssociatedTokenAddressSync(
+    USDC_MINT,
+    wallet.publicKey,
+    false,
+    TOKEN_PROGRAM_ID
+  );
+
+  let holder: Keypair;
+  let holderUsdcAccount: PublicKey;
+
+  // A property whose shares are all minted to the wallet under the legacy token program
+  const createProperty = async (name: string) => {
+    const asset = Keypair.generate();
+    const mint = Keypair.generate();
+
+    await program.methods
+      .createFungibleToken(6, new BN(5), [], null)
+      .accountsPartial({
+        payer: wallet.publicKey,
+        mint: mint.publicKey,
+        tokenAccount: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          wallet.publicKey,
+          false,
+          TOKEN_PROGRAM_ID
+        ),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    await program.methods
+      .createNonFungibleToken({
+        name,
+        uri: "https://example.com/distribution-nft.json",
+        attributes: [],
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset: asset.publicKey,
+        ftMint: mint.publicKey,
+      })
+      .signers([asset])
+      .rpc();
+
+    return { asset: asset.publicKey, mint: mint.publicKey };
+  };
+
+  before(async () => {
+    holder = Keypair.generate();
+    const fundTx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: wallet.publicKey,
+        toPubkey: holder.publicKey,
+        lamports: 0.02 * anchor.web3.LAMPORTS_PER_SOL,
+      })
+    );
+    await provider.sendAndConfirm(fundTx);
+
+    holderUsdcAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        USDC_MINT,
+        holder.publicKey
+      )
+    ).address;
+  });
+
+  describe("Rental Income Distribution", () => {
+    const period = new BN(202610);
+    let incomeAsset: PublicKey;
+    let incomeFtMint: PublicKey;
+    let distribution: PublicKey;
+    let walletShareAccount: PublicKey;
+    let holderShareAccount: PublicKey;
+
+    // Balances when the income is deposited: the holder owns 1 of the 5 shares
+    const snapshot = [
+      { holder: () => wallet.publicKey, shares: new BN(4_000_000) },
+      { holder: () => holder.publicKey, shares: new BN(1_000_000) },
+    ];
+    let leaves: Buffer[];
+    let snapshotRoot: Buffer;
+
+    const claim = (claimer: Keypair | null, index: number, shares: BN, proof: Buffer[]) => {
+      const builder = program.methods
+        .claimDistribution(index, shares, proof.map((node) => Array.from(node)))
+        .accountsPartial({
+          holder: claimer ? claimer.publicKey : wallet.publicKey,
+          distribution,
+          usdcMint: USDC_MINT,
+          usdcTokenProgram: TOKEN_PROGRAM_ID,
+        });
+      return claimer ? builder.signers([claimer]).rpc() : builder.rpc();
+    };
+
+    before(async () => {
+      ({ asset: incomeAsset, mint: incomeFtMint } = await createProperty("Rental Property NFT"));
+
+      walletShareAccount = getAssociatedTokenAddressSync(
+        incomeFtMint,
+        wallet.publicKey,
+        false,
+        TOKEN_PROGRAM_ID
+      );
+      holderShareAccount = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          wallet.payer,
+          incomeFtMint,
+          holder.publicKey,
+          false,
+          undefined,
+          undefined,
+          TOKEN_PROGRAM_ID
+        )
+      ).address;
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        walletShareAccount,
+        holderShareAccount,
+        wallet.publicKey,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+
+      leaves = snapshot.map((entry, index) => leafHash(index, entry.holder(), entry.shares));
+      snapshotRoot = nodeHash(leaves[0], leaves[1]);
+
+      [distribution] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("distribution"),
+          incomeAsset.toBuffer(),
+          period.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Rejects deposits from anyone but the asset manager", async () => {
+      try {
+        await program.methods
+          .depositDistribution(period, new BN(100_000), Array.from(snapshotRoot))
+          .accountsPartial({
+            depositor: holder.publicKey,
+            asset: incomeAsset,
+            usdcMint: USDC_MINT,
+            depositorUsdcAccount: holderUsdcAccount,
+            usdcTokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([holder])
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "UnauthorizedDistributionDeposit");
+      }
+    });
+
+    it("Deposits a period of rental income with a balance snapshot", async () => {
+      await program.methods
+        .depositDistribution(period, new BN(1_000_000), Array.from(snapshotRoot))
+        .accountsPartial({
+          depositor: wallet.publicKey,
+          asset: incomeAsset,
+          usdcMint: USDC_MINT,
+          depositorUsdcAccount: walletUsdcAccount,
+          usdcTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const state = await program.account.distribution.fetch(distribution);
+      assert.equal(state.amount.toString(), "1000000");
+      assert.equal(state.totalShares.toString(), "5000000");
+      assert.equal(state.ftMint.toString(), incomeFtMint.toString());
+      assert.deepEqual(Buffer.from(state.snapshotRoot), snapshotRoot);
+      assert.isAbove(state.claimEndTime.toNumber(), Math.floor(Date.now() / 1000));
+    });
+
+    it("Pays by the snapshot after the shares changed hands", async () => {
+      // The holder sells their share to a new wallet after the snapshot
+      const buyer = Keypair.generate();
+      const fundTx = new anchor.web3.Transaction().add(
+        anchor.web3.SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: buyer.publicKey,
+          lamports: 0.02 * anchor.web3.LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(fundTx);
+
+      const buyerShareAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        incomeFtMint,
+        buyer.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        holderShareAccount,
+        buyerShareAccount.address,
+        holder,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+
+      // The buyer's shares were not in the snapshot, so they can't be paid a second time
+      try {
+        await claim(buyer, 1, new BN(1_000_000), [leaves[0]]);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidMerkleProof");
+      }
+
+      const balanceBefore = await getAccount(provider.connection, holderUsdcAccount);
+      await claim(holder, 1, new BN(1_000_000), [leaves[0]]);
+      const balanceAfter = await getAccount(provider.connection, holderUsdcAccount);
+      assert.equal(Number(balanceAfter.amount) - Number(balanceBefore.amount), 200_000);
+
+      await claim(null, 0, new BN(4_000_000), [leaves[1]]);
+
+      const state = await program.account.distribution.fetch(distribution);
+      assert.equal(state.claimed.toString(), "1000000");
+      assert.equal(state.claimedShares.toString(), "5000000");
+    });
+
+    it("Prevents claiming twice", async () => {
+      try {
+        await claim(holder, 1, new BN(1_000_000), [leaves[0]]);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "already in use");
+      }
+    });
+
+    it("Keeps unclaimed income in the distribution until the claim window closes", async () => {
+      try {
+        await program.methods
+          .sweepDistribution()
+          .accountsPartial({
+            payer: wallet.publicKey,
+            distribution,
+            usdcMint: USDC_MINT,
+            usdcTokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "DistributionClaimsOpen");
+      }
+    });
+  });
+
+  describe("Merkle Distribution", () => {
+    const epoch = new BN(1);
+    let merkleAsset: PublicKey;
+    let merkleDistribution: PublicKey;
+    let leaves: Buffer[];
+    let root: Buffer;
+
+    const allocations = [
+      { holder: () => wallet.publicKey, amount: new BN(300_000) },
+      { holder: () => holder.publicKey, amount: new BN(200_000) },
+    ];
+
+    const claimMerkle = (amount: BN) =>
+      program.methods
+        .claimMerkleDistribution(1, amount, [Array.from(leaves[0])])
+        .accountsPartial({
+          holder: holder.publicKey,
+          merkleDistribution,
+          usdcMint: USDC_MINT,
+          usdcTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([holder])
+        .rpc();
+
+    before(async () => {
+      ({ asset: merkleAsset } = await createProperty("Merkle Property NFT"));
+
+      leaves = allocations.map((allocation, index) =>
+        leafHash(index, allocation.holder(), allocation.amount)
+      );
+      root = nodeHash(leaves[0], leaves[1]);
+
+      [merkleDistribution] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("merkle_distribution"),
+          merkleAsset.toBuffer(),
+          epoch.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Publishes and funds a merkle root", async () => {
+      await program.methods
+        .createMerkleDistribution({
+          epoch,
+          merkleRoot: Array.from(root),
+          numLeaves: leaves.length,
+          totalAmount: new BN(500_000),
+        })
+        .accountsPartial({
+          distributor: wallet.publicKey,
+          asset: merkleAsset,
+          usdcMint: USDC_MINT,
+          distributorUsdcAccount: walletUsdcAccount,
+          usdcTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const state = await program.account.merkleDistribution.fetch(merkleDistribution);
+      assert.equal(state.numLeaves, 2);
+      assert.deepEqual(Buffer.from(state.merkleRoot), root);
+    });
+
+    it("Rejects a proof for the wrong amount", async () => {
+      try {
+        await claimMerkle(new BN(400_000));
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidMerkleProof");
+      }
+    });
+
+    it("Pays a holder with a valid proof once", async () => {
+      const balanceBefore = await getAccount(provider.connection, holderUsdcAccount);
+
+      await claimMerkle(new BN(200_000));
+
+      const balanceAfter = await getAccount(provider.connection, holderUsdcAccount);
+      assert.equal(Number(balanceAfter.amount) - Number(balanceBefore.amount), 200_000);
+
+      try {
+        await claimMerkle(new BN(200_000));
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AlreadyClaimed");
+      }
+    });
+  });
+});
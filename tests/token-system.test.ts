@@ -4,7 +4,12 @@ import { RwaContract } from "../target/types/rwa_contract";
 import { Keypair, SystemProgram } from "@solana/web3.js";
 import {
   TOKEN_2022_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
   getMint,
+  getTokenMetadata,
+  transfer,
 } from "@solana/spl-token";
 import { MPL_CORE_PROGRAM_ID } from "@metaplex-foundation/mpl-core";
 import { expect } from "chai";
@@ -23,7 +28,7 @@ describe("Token System", () => {
       const supply = 100;
 
       await program.methods
-        .createFungibleToken(decimals, supply)
+        .createFungibleToken(decimals, new anchor.BN(supply), [], null)
         .accounts({
           mint: mintKeypair.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -45,6 +50,85 @@ describe("Token System", () => {
       );
       expect(mintInfo.mintAuthority).to.be.null; // Authority should be revoked
     });
+
+    it("Distributes a large supply across initial holders", async () => {
+      const mintKeypair = Keypair.generate();
+      const investor = Keypair.generate();
+      const platformTreasury = Keypair.generate();
+      const supply = new anchor.BN(1_000_000);
+
+      const investorTokenAccount = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        investor.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const treasuryTokenAccount = getAssociatedTokenAddressSync(
+        mintKeypair.publicKey,
+        platformTreasury.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await program.methods
+        .createFungibleToken(6, supply, [
+          { recipient: investor.publicKey, amount: new anchor.BN(600_000) },
+          { recipient: platformTreasury.publicKey, amount: new anchor.BN(50_000) },
+        ], null)
+        .accounts({
+          mint: mintKeypair.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: investor.publicKey, isSigner: false, isWritable: false },
+          { pubkey: investorTokenAccount, isSigner: false, isWritable: true },
+          { pubkey: platformTreasury.publicKey, isSigner: false, isWritable: false },
+          { pubkey: treasuryTokenAccount, isSigner: false, isWritable: true },
+        ])
+        .signers([mintKeypair])
+        .rpc();
+
+      const balanceOf = async (tokenAccount: anchor.web3.PublicKey) =>
+        (
+          await getAccount(
+            provider.connection,
+            tokenAccount,
+            undefined,
+            TOKEN_2022_PROGRAM_ID
+          )
+        ).amount.toString();
+
+      expect(await balanceOf(investorTokenAccount)).to.equal("600000000000");
+      expect(await balanceOf(treasuryTokenAccount)).to.equal("50000000000");
+      expect(
+        await balanceOf(
+          getAssociatedTokenAddressSync(
+            mintKeypair.publicKey,
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          )
+        )
+      ).to.equal("350000000000");
+    });
+
+    it("Rejects a supply that overflows the decimals", async () => {
+      const mintKeypair = Keypair.generate();
+
+      try {
+        await program.methods
+          .createFungibleToken(9, new anchor.BN("100000000000000"), [], null)
+          .accounts({
+            mint: mintKeypair.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([mintKeypair])
+          .rpc();
+        expect.fail("Should have rejected an overflowing supply");
+      } catch (error) {
+        expect(error.message).to.include("Overflow");
+      }
+    });
   });
 
   describe("Non-Fungible Token (NFT)", () => {
@@ -55,7 +139,7 @@ describe("Token System", () => {
       ftMintKeypair = Keypair.generate();
 
       await program.methods
-        .createFungibleToken(6, 100)
+        .createFungibleToken(6, new anchor.BN(100), [], null)
         .accounts({
           mint: ftMintKeypair.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -70,6 +154,7 @@ describe("Token System", () => {
       const args = {
         name: "Property NFT",
         uri: "https://example.com/nft-metadata.json",
+        attributes: [],
       };
 
       await program.methods
@@ -97,6 +182,635 @@ describe("Token System", () => {
       expect(assetState.ftMint.toString()).to.equal(
         ftMintKeypair.publicKey.toString()
       );
+
+      // Verify the asset config defaults to the tokenizer as authority
+      const [assetConfigPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_config"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const assetConfig = await program.account.assetConfig.fetch(assetConfigPda);
+      expect(assetConfig.authority.toString()).to.equal(wallet.publicKey.toString());
+      expect(assetConfig.guardian.toString()).to.equal(wallet.publicKey.toString());
+      expect(assetConfig.timelockDelay.toNumber()).to.equal(2 * 24 * 60 * 60);
+      expect(assetConfig.supermajorityBps).to.equal(7500);
+    });
+  });
+
+  describe("Atomic Tokenization", () => {
+    it("Tokenizes a property with a fixed share supply", async () => {
+      const assetKeypair = Keypair.generate();
+      const ftMintKeypair = Keypair.generate();
+
+      await program.methods
+        .tokenizeProperty({
+          name: "Atomic Property",
+          symbol: "ATOM",
+          uri: "https://example.com/atomic-property.json",
+          attributes: [],
+          decimals: 6,
+          supply: new anchor.BN(1_000),
+          allocations: [],
+          regulatoryFreeze: false,
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .signers([assetKeypair, ftMintKeypair])
+        .rpc();
+
+      const mintInfo = await getMint(
+        provider.connection,
+        ftMintKeypair.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(mintInfo.mintAuthority).to.be.null;
+      expect(mintInfo.freezeAuthority).to.be.null;
+      expect(mintInfo.supply.toString()).to.equal("1000000000");
+
+      const tokenAccount = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(
+          ftMintKeypair.publicKey,
+          wallet.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(tokenAccount.amount.toString()).to.equal("1000000000");
+
+      const [assetStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const assetState = await program.account.assetState.fetch(assetStatePda);
+      expect(assetState.ftMint.toString()).to.equal(
+        ftMintKeypair.publicKey.toString()
+      );
+      expect(assetState.totalShares.toString()).to.equal("1000000000");
+
+      // Wallets can read the property name and asset straight from the share mint
+      const metadata = await getTokenMetadata(
+        provider.connection,
+        ftMintKeypair.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(metadata.name).to.equal("Atomic Property");
+      expect(metadata.symbol).to.equal("ATOM");
+      expect(metadata.additionalMetadata).to.deep.include([
+        "property_asset",
+        assetKeypair.publicKey.toString(),
+      ]);
+    });
+
+    it("Rejects binding a share mint that still has a mint authority", async () => {
+      const assetKeypair = Keypair.generate();
+      const mint = await createMint(
+        provider.connection,
+        wallet.payer,
+        wallet.publicKey,
+        null,
+        6,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      try {
+        await program.methods
+          .createNonFungibleToken({
+            name: "Unsafe Property",
+            uri: "https://example.com/unsafe-property.json",
+            attributes: [],
+          })
+          .accountsPartial({
+            payer: wallet.publicKey,
+            asset: assetKeypair.publicKey,
+            ftMint: mint,
+            systemProgram: SystemProgram.programId,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          })
+          .signers([wallet.payer, assetKeypair])
+          .rpc();
+        expect.fail("Should have rejected a mint with a live mint authority");
+      } catch (error) {
+        expect(error.message).to.include("InvalidShareMint");
+      }
+    });
+  });
+
+  describe("Regulatory Holds", () => {
+    const assetKeypair = Keypair.generate();
+    const ftMintKeypair = Keypair.generate();
+    const outsider = Keypair.generate();
+    let holderTokenAccount: anchor.web3.PublicKey;
+
+    before(async () => {
+      // The freeze PDA of the asset is known before the asset exists
+      await program.methods
+        .createFungibleToken(6, new anchor.BN(100), [], assetKeypair.publicKey)
+        .accounts({
+          mint: ftMintKeypair.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([ftMintKeypair])
+        .rpc();
+
+      await program.methods
+        .createNonFungibleToken({
+          name: "Held Property",
+          uri: "https://example.com/held-property.json",
+          attributes: [],
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          systemProgram: SystemProgram.programId,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .signers([wallet.payer, assetKeypair])
+        .rpc();
+
+      holderTokenAccount = getAssociatedTokenAddressSync(
+        ftMintKeypair.publicKey,
+        wallet.publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+    });
+
+    it("Sets the asset's freeze PDA as freeze authority", async () => {
+      const [freezeAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("freeze_authority"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+
+      const mintInfo = await getMint(
+        provider.connection,
+        ftMintKeypair.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(mintInfo.freezeAuthority.toString()).to.equal(
+        freezeAuthority.toString()
+      );
+    });
+
+    it("Lets the compliance officer freeze and thaw a holder", async () => {
+      await program.methods
+        .freezeHolder()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          holderTokenAccount,
+          voteState: null,
+          queuedAction: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      let account = await getAccount(
+        provider.connection,
+        holderTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(account.isFrozen).to.be.true;
+
+      // Frozen shares can't move
+      try {
+        await transfer(
+          provider.connection,
+          wallet.payer,
+          holderTokenAccount,
+          holderTokenAccount,
+          wallet.publicKey,
+          1,
+          [],
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        );
+        expect.fail("Transfer from a frozen account should have failed");
+      } catch (error) {
+        expect(error.toString()).to.include("0x11");
+      }
+
+      await program.methods
+        .thawHolder()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          holderTokenAccount,
+          voteState: null,
+          queuedAction: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      account = await getAccount(
+        provider.connection,
+        holderTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(account.isFrozen).to.be.false;
+    });
+
+    it("Rejects freezes from anyone else without a proposal", async () => {
+      try {
+        await program.methods
+          .freezeHolder()
+          .accountsPartial({
+            executor: outsider.publicKey,
+            asset: assetKeypair.publicKey,
+            ftMint: ftMintKeypair.publicKey,
+            holderTokenAccount,
+            voteState: null,
+            queuedAction: null,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([outsider])
+          .rpc();
+        expect.fail("Freeze by an outsider should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedComplianceAction");
+      }
+    });
+  });
+
+  describe("Property Metadata", () => {
+    const assetKeypair = Keypair.generate();
+    const ftMintKeypair = Keypair.generate();
+
+    before(async () => {
+      await program.methods
+        .tokenizeProperty({
+          name: "Old Name",
+          symbol: "PROP",
+          uri: "https://example.com/old.json",
+          attributes: [
+            { key: "parcel_id", value: "PARCEL-0042" },
+            { key: "square_meters", value: "120" },
+            { key: "appraisal_value", value: "450000" },
+          ],
+          decimals: 6,
+          supply: new anchor.BN(100),
+          allocations: [],
+          regulatoryFreeze: false,
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .signers([assetKeypair, ftMintKeypair])
+        .rpc();
+    });
+
+    it("Lets the asset manager update name and URI", async () => {
+      await program.methods
+        .updatePropertyMetadata({
+          name: "Renamed Property",
+          uri: "https://example.com/renamed.json",
+        })
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: null,
+          queuedAction: null,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .rpc();
+
+      const assetData = (
+        await provider.connection.getAccountInfo(assetKeypair.publicKey)
+      ).data.toString();
+      expect(assetData).to.include("Renamed Property");
+      expect(assetData).to.include("https://example.com/renamed.json");
+    });
+
+    it("Updates and removes single property attributes", async () => {
+      const updateAttribute = (key: string, value: string) =>
+        program.methods
+          .updatePropertyAttribute(key, value)
+          .accountsPartial({
+            assetManager: wallet.publicKey,
+            asset: assetKeypair.publicKey,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          })
+          .rpc();
+
+      await updateAttribute("appraisal_value", "475000");
+      await updateAttribute("appraisal_date", "2025-06-30");
+      await updateAttribute("square_meters", "");
+
+      const assetData = (
+        await provider.connection.getAccountInfo(assetKeypair.publicKey)
+      ).data.toString();
+      expect(assetData).to.include("PARCEL-0042");
+      expect(assetData).to.include("475000");
+      expect(assetData).to.include("2025-06-30");
+      expect(assetData).to.not.include("450000");
+      expect(assetData).to.not.include("square_meters");
+    });
+
+    it("Rejects updates from anyone else without a proposal", async () => {
+      const outsider = Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          outsider.publicKey,
+          anchor.web3.LAMPORTS_PER_SOL
+        )
+      );
+
+      try {
+        await program.methods
+          .updatePropertyMetadata({ name: "Hijacked", uri: null })
+          .accountsPartial({
+            executor: outsider.publicKey,
+            asset: assetKeypair.publicKey,
+            voteState: null,
+            queuedAction: null,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          })
+          .signers([outsider])
+          .rpc();
+        expect.fail("Update by an outsider should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedMetadataUpdate");
+      }
+    });
+  });
+
+  describe("Full Buyout", () => {
+    const assetKeypair = Keypair.generate();
+    const ftMintKeypair = Keypair.generate();
+    const minorityHolder = Keypair.generate();
+
+    const tokenAccountOf = (owner: anchor.web3.PublicKey) =>
+      getAssociatedTokenAddressSync(
+        ftMintKeypair.publicKey,
+        owner,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+    const redeem = () =>
+      program.methods
+        .redeemProperty()
+        .accountsPartial({
+          redeemer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          redeemerTokenAccount: tokenAccountOf(wallet.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .tokenizeProperty({
+          name: "Buyout Property",
+          symbol: "BUY",
+          uri: "https://example.com/buyout.json",
+          attributes: [],
+          decimals: 6,
+          supply: new anchor.BN(10),
+          allocations: [
+            { recipient: minorityHolder.publicKey, amount: new anchor.BN(1) },
+          ],
+          regulatoryFreeze: false,
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: minorityHolder.publicKey, isSigner: false, isWritable: false },
+          {
+            pubkey: tokenAccountOf(minorityHolder.publicKey),
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .signers([assetKeypair, ftMintKeypair])
+        .rpc();
+    });
+
+    const retire = () =>
+      program.methods
+        .retireAsset()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: null,
+          queuedAction: null,
+          collection: null,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .rpc();
+
+    it("Rejects retiring an active property without a proposal", async () => {
+      try {
+        await retire();
+        expect.fail("Retiring without a proposal should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedRetirement");
+      }
+    });
+
+    it("Rejects redemption without all shares", async () => {
+      try {
+        await redeem();
+        expect.fail("Redemption with 90% of the shares should have failed");
+      } catch (error) {
+        expect(error.message).to.include("IncompleteShares");
+      }
+    });
+
+    it("Burns all shares and hands over the NFT", async () => {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(
+          minorityHolder.publicKey,
+          anchor.web3.LAMPORTS_PER_SOL
+        )
+      );
+      await transfer(
+        provider.connection,
+        minorityHolder,
+        tokenAccountOf(minorityHolder.publicKey),
+        tokenAccountOf(wallet.publicKey),
+        minorityHolder,
+        1_000_000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await redeem();
+
+      const mintInfo = await getMint(
+        provider.connection,
+        ftMintKeypair.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      expect(mintInfo.supply.toString()).to.equal("0");
+
+      // mpl-core assets store the owner right after the account key byte
+      const assetData = (
+        await provider.connection.getAccountInfo(assetKeypair.publicKey)
+      ).data;
+      expect(
+        new anchor.web3.PublicKey(assetData.subarray(1, 33)).toString()
+      ).to.equal(wallet.publicKey.toString());
+
+      const [assetStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const assetState = await program.account.assetState.fetch(assetStatePda);
+      expect(assetState.status).to.deep.equal({ redeemed: {} });
+    });
+
+    it("Lets the redeemer retire the property", async () => {
+      await retire();
+
+      // Burned mpl-core assets keep a single uninitialized key byte
+      const assetInfo = await provider.connection.getAccountInfo(
+        assetKeypair.publicKey
+      );
+      expect(assetInfo.data.length).to.equal(1);
+
+      const [assetStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const assetState = await program.account.assetState.fetch(assetStatePda);
+      expect(assetState.status).to.deep.equal({ retired: {} });
+    });
+  });
+  describe("Portfolios", () => {
+    const collectionKeypair = Keypair.generate();
+    const [portfolioPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("portfolio"), collectionKeypair.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const tokenizeInto = (
+      assetKeypair: Keypair,
+      ftMintKeypair: Keypair,
+      portfolio: anchor.web3.PublicKey | null
+    ) =>
+      program.methods
+        .tokenizeProperty({
+          name: "Fund Property",
+          symbol: "FUND",
+          uri: "https://example.com/fund-property.json",
+          attributes: [],
+          decimals: 6,
+          supply: new anchor.BN(100),
+          allocations: [],
+          regulatoryFreeze: false,
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          ftMint: ftMintKeypair.publicKey,
+          collection: collectionKeypair.publicKey,
+          portfolio,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .signers([assetKeypair, ftMintKeypair])
+        .rpc();
+
+    it("Creates a portfolio collection under the portfolio PDA", async () => {
+      await program.methods
+        .createPortfolio({
+          name: "Residential Fund I",
+          uri: "https://example.com/fund.json",
+        })
+        .accountsPartial({
+          manager: wallet.publicKey,
+          collection: collectionKeypair.publicKey,
+          portfolio: portfolioPda,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .signers([collectionKeypair])
+        .rpc();
+
+      const portfolio = await program.account.portfolioState.fetch(portfolioPda);
+      expect(portfolio.collection.toString()).to.equal(
+        collectionKeypair.publicKey.toString()
+      );
+      expect(portfolio.manager.toString()).to.equal(wallet.publicKey.toString());
+
+      // mpl-core collections store the update authority right after the account key byte
+      const collectionData = (
+        await provider.connection.getAccountInfo(collectionKeypair.publicKey)
+      ).data;
+      expect(
+        new anchor.web3.PublicKey(collectionData.subarray(1, 33)).toString()
+      ).to.equal(portfolioPda.toString());
+    });
+
+    it("Tokenizes a property into the portfolio", async () => {
+      const assetKeypair = Keypair.generate();
+      await tokenizeInto(assetKeypair, Keypair.generate(), portfolioPda);
+
+      const [assetStatePda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), assetKeypair.publicKey.toBuffer()],
+        program.programId
+      );
+      const assetState = await program.account.assetState.fetch(assetStatePda);
+      expect(assetState.collection.toString()).to.equal(
+        collectionKeypair.publicKey.toString()
+      );
+
+      // The property stays manageable through its authority PDA as update delegate
+      await program.methods
+        .updatePropertyMetadata({ name: "Fund Property #1", uri: null })
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: null,
+          queuedAction: null,
+          collection: collectionKeypair.publicKey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+        })
+        .rpc();
+
+      const assetData = (
+        await provider.connection.getAccountInfo(assetKeypair.publicKey)
+      ).data.toString();
+      expect(assetData).to.include("Fund Property #1");
+    });
+
+    it("Rejects a collection without its portfolio", async () => {
+      try {
+        await tokenizeInto(Keypair.generate(), Keypair.generate(), null);
+        expect.fail("Tokenizing into a bare collection should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidPortfolio");
+      }
     });
   });
 });
@@ -0,0 +1,428 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { RwaContract } from "../target/types/rwa_contract";
+import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAccount,
+  TOKEN_PROGRAM_ID,
+  getOrCreateAssociatedTokenAccount,
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { createHash } from "crypto";
+
+describe("Treasury System Tests", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.RwaContract as Program<RwaContract>;
+  const wallet = provider.wallet as anchor.Wallet;
+
+  // USDC devnet mint
+  const USDC_MINT = new PublicKey("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr");
+
+  const walletUsdcAccount = getAssociatedTokenAddressSync(
+    USDC_MINT,
+    wallet.publicKey,
+    false,
+    TOKEN_PROGRAM_ID
+  );
+
+  const treasuryPda = (asset: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), asset.toBuffer()],
+      program.programId
+    )[0];
+
+  // A property without a timelock whose shares are all minted to the wallet, with 1 USDC in
+  // its treasury
+  const createProperty = async (name: string) => {
+    const asset = Keypair.generate();
+    const mint = Keypair.generate();
+
+    await program.methods
+      .createFungibleToken(6, new BN(10), [], null)
+      .accountsPartial({
+        payer: wallet.publicKey,
+        mint: mint.publicKey,
+        tokenAccount: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          wallet.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    await program.methods
+      .createNonFungibleToken({
+        name,
+        uri: "https://example.com/treasury-nft.json",
+        attributes: [],
+        timelockDelay: new BN(0),
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset: asset.publicKey,
+        ftMint: mint.publicKey,
+      })
+      .signers([asset])
+      .rpc();
+
+    await program.methods
+      .depositTreasury(new BN(1_000_000))
+      .accountsPartial({
+        depositor: wallet.publicKey,
+        asset: asset.publicKey,
+        usdcMint: USDC_MINT,
+        depositorUsdcAccount: walletUsdcAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    // Lock most shares so the wallet alone passes the treasury proposals
+    await program.methods
+      .lockShares(new BN(9_000_000))
+      .accountsPartial({
+        owner: wallet.publicKey,
+        ftMint: mint.publicKey,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          mint.publicKey,
+          wallet.publicKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return { asset: asset.publicKey, mint: mint.publicKey };
+  };
+
+  // Passes and queues vote round `round` of the property carrying `action`
+  const passProposal = async (
+    asset: PublicKey,
+    mint: PublicKey,
+    round: number,
+    action: any
+  ) => {
+    const voteRound = new BN(round);
+    const description = "Treasury proposal " + round;
+    const [assetState] = PublicKey.findProgramAddressSync(
+      [Buffer.from("asset_state"), asset.toBuffer()],
+      program.programId
+    );
+    const [voteState] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("vote_state"),
+        asset.toBuffer(),
+        wallet.publicKey.toBuffer(),
+        voteRound.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [queuedAction] = PublicKey.findProgramAddressSync(
+      [Buffer.from("queued_action"), voteState.toBuffer()],
+      program.programId
+    );
+    const tokenAccount = getAssociatedTokenAddressSync(
+      mint,
+      wallet.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await program.methods
+      .createVoteRound({
+        description,
+        metadataUri: "https://example.com/proposals/treasury.json",
+        contentHash: Array.from(createHash("sha256").update(description).digest()),
+        votingEndTime: new BN(Math.floor(Date.now() / 1000) + 10),
+        votingMode: { tokenWeighted: {} },
+        action,
+      })
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        tokenAccount,
+        assetState,
+        voteState,
+      })
+      .rpc();
+
+    await program.methods
+      .vote(wallet.publicKey, voteRound, 1)
+      .accountsPartial({
+        voter: wallet.publicKey,
+        asset,
+        ftMint: mint,
+        assetState,
+        voteState,
+        voterLock: PublicKey.findProgramAddressSync(
+          [Buffer.from("voter_lock"), mint.toBuffer(), wallet.publicKey.toBuffer()],
+          program.programId
+        )[0],
+      })
+      .rpc();
+
+    console.log("Waiting 12 seconds for voting to end...");
+    await new Promise((resolve) => setTimeout(resolve, 12000));
+
+    await program.methods
+      .finalizeVoteRound()
+      .accountsPartial({
+        asset,
+        votingCreator: wallet.publicKey,
+        voteState,
+      })
+      .rpc();
+
+    await program.methods
+      .queueProposal()
+      .accountsPartial({
+        payer: wallet.publicKey,
+        asset,
+        voteState,
+        queuedAction,
+      })
+      .rpc();
+
+    return { voteState, queuedAction };
+  };
+
+  describe("Treasury Allowance", () => {
+    const vendor = Keypair.generate().publicKey;
+    let asset: PublicKey;
+    let mint: PublicKey;
+
+    const spend = (amount: number) =>
+      program.methods
+        .spendTreasuryAllowance(new BN(amount))
+        .accountsPartial({
+          assetManager: wallet.publicKey,
+          asset,
+          usdcMint: USDC_MINT,
+          recipient: vendor,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    before(async () => {
+      ({ asset, mint } = await createProperty("Treasury Property NFT"));
+    });
+
+    it("Collects deposits into the treasury", async () => {
+      const treasuryUsdc = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(USDC_MINT, treasuryPda(asset), true, TOKEN_PROGRAM_ID)
+      );
+      assert.equal(treasuryUsdc.amount.toString(), "1000000");
+    });
+
+    it("Rejects manager spending without an allowance", async () => {
+      try {
+        await spend(100_000);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AllowanceExceeded");
+      }
+    });
+
+    it("Lets the manager spend up to the allowance the holders voted for", async () => {
+      const { voteState, queuedAction } = await passProposal(asset, mint, 0, {
+        updateTreasuryConfig: {
+          assetManager: null,
+          managerAllowance: new BN(300_000),
+          allowancePeriod: null,
+          reserveTarget: null,
+          managementFeeBps: null,
+          debtServiceAmount: null,
+          debtServiceRecipient: null,
+        },
+      });
+
+      await program.methods
+        .executeTreasuryConfigUpdate()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset,
+          voteState,
+          queuedAction,
+        })
+        .rpc();
+
+      await spend(200_000);
+      const vendorUsdc = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(USDC_MINT, vendor, false, TOKEN_PROGRAM_ID)
+      );
+      assert.equal(vendorUsdc.amount.toString(), "200000");
+
+      try {
+        await spend(200_000);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "AllowanceExceeded");
+      }
+    });
+  });
+
+  describe("Distribution Waterfall", () => {
+    const lender = Keypair.generate().publicKey;
+    const vendor = Keypair.generate().publicKey;
+    const period = new BN(202611);
+    let asset: PublicKey;
+    let mint: PublicKey;
+    let lenderUsdcAccount: PublicKey;
+    let treasuryUsdcAccount: PublicKey;
+    let distribution: PublicKey;
+    let payment: { voteState: PublicKey; queuedAction: PublicKey };
+
+    const runWaterfall = (debtServiceUsdcAccount: PublicKey | null, runPeriod = period) =>
+      program.methods
+        .runWaterfall(runPeriod, new Array(32).fill(0))
+        .accountsPartial({
+          assetManager: wallet.publicKey,
+          asset,
+          usdcMint: USDC_MINT,
+          debtServiceUsdcAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    before(async () => {
+      ({ asset, mint } = await createProperty("Waterfall Property NFT"));
+
+      lenderUsdcAccount = (
+        await getOrCreateAssociatedTokenAccount(
+          provider.connection,
+          wallet.payer,
+          USDC_MINT,
+          lender
+        )
+      ).address;
+      treasuryUsdcAccount = getAssociatedTokenAddressSync(
+        USDC_MINT,
+        treasuryPda(asset),
+        true,
+        TOKEN_PROGRAM_ID
+      );
+      [distribution] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("distribution"),
+          asset.toBuffer(),
+          period.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      // Keep 0.2 USDC in reserve, take a 10% fee, then pay 0.1 USDC of debt service
+      const config = await passProposal(asset, mint, 0, {
+        updateTreasuryConfig: {
+          assetManager: null,
+          managerAllowance: null,
+          allowancePeriod: null,
+          reserveTarget: new BN(200_000),
+          managementFeeBps: 1_000,
+          debtServiceAmount: new BN(100_000),
+          debtServiceRecipient: lender,
+        },
+      });
+      await program.methods
+        .executeTreasuryConfigUpdate()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset,
+          voteState: config.voteState,
+          queuedAction: config.queuedAction,
+        })
+        .rpc();
+
+      // A vendor payment the holders approved but nobody executed yet
+      payment = await passProposal(asset, mint, 1, {
+        treasuryPayment: { recipient: vendor, amount: new BN(300_000) },
+      });
+    });
+
+    it("Requires the lender's account when debt service is due", async () => {
+      try {
+        await runWaterfall(null);
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "InvalidDebtServiceAccount");
+      }
+    });
+
+    it("Fills the reserve, pays fee and debt service, then distributes the rest", async () => {
+      const lenderBefore = await getAccount(provider.connection, lenderUsdcAccount);
+
+      await runWaterfall(lenderUsdcAccount);
+
+      // The queued 0.3 USDC payment stays on top of the reserve
+      const treasuryUsdc = await getAccount(provider.connection, treasuryUsdcAccount);
+      assert.equal(treasuryUsdc.amount.toString(), "500000");
+
+      const lenderAfter = await getAccount(provider.connection, lenderUsdcAccount);
+      assert.equal(Number(lenderAfter.amount) - Number(lenderBefore.amount), 100_000);
+
+      // 0.5 USDC above the reserve and the payment, minus 0.05 fee and 0.1 debt service
+      const state = await program.account.distribution.fetch(distribution);
+      assert.equal(state.amount.toString(), "350000");
+      assert.equal(state.totalShares.toString(), "10000000");
+      assert.equal(state.debtService.toString(), "100000");
+      assert.equal(state.debtServiceRecipient.toString(), lender.toString());
+
+      const [assetState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), asset.toBuffer()],
+        program.programId
+      );
+      const assetStateAccount = await program.account.assetState.fetch(assetState);
+      assert.equal(assetStateAccount.treasuryIncome.toString(), "0");
+      assert.isAbove(assetStateAccount.lastWaterfallTime.toNumber(), 0);
+    });
+
+    it("Rejects a second waterfall within the same period", async () => {
+      try {
+        await runWaterfall(lenderUsdcAccount, period.addn(1));
+        assert.fail("Should have failed");
+      } catch (error) {
+        assert.include(error.message, "WaterfallTooEarly");
+      }
+    });
+
+    it("Still pays the queued treasury payment afterwards", async () => {
+      await program.methods
+        .executeTreasuryPayment()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset,
+          usdcMint: USDC_MINT,
+          voteState: payment.voteState,
+          queuedAction: payment.queuedAction,
+          recipient: vendor,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const vendorUsdc = await getAccount(
+        provider.connection,
+        getAssociatedTokenAddressSync(USDC_MINT, vendor, false, TOKEN_PROGRAM_ID)
+      );
+      assert.equal(vendorUsdc.amount.toString(), "300000");
+
+      const [assetState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), asset.toBuffer()],
+        program.programId
+      );
+      const state = await program.account.assetState.fetch(assetState);
+      assert.equal(state.committedUsdc.toString(), "0");
+    });
+  });
+});
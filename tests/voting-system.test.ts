@@ -8,8 +8,11 @@ import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAccount,
   getMint,
+  getOrCreateAssociatedTokenAccount,
+  transfer,
 } from "@solana/spl-token";
 import { assert } from "chai";
+import { createHash } from "crypto";
 
 describe("Voting System Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -18,6 +21,51 @@ describe("Voting System Tests", () => {
   const program = anchor.workspace.RwaContract as Program<RwaContract>;
   const wallet = provider.wallet as anchor.Wallet;
 
+  // SHA-256 of the off-chain proposal document, as stored in VoteState.contentHash
+  const proposalHash = (document: string) =>
+    Array.from(createHash("sha256").update(document).digest());
+
+  // Vote round arguments with the voting period counted from now
+  const roundArgs = (
+    description: string,
+    votingPeriod = 3600,
+    action = null,
+    votingMode: any = { tokenWeighted: {} }
+  ) => ({
+    description,
+    metadataUri: "https://example.com/proposals/" + encodeURIComponent(description) + ".json",
+    contentHash: proposalHash(description),
+    votingEndTime: new BN(Math.floor(Date.now() / 1000) + votingPeriod),
+    votingMode,
+    action,
+  });
+
+  // Escrow holding a wallet's voting shares for a share mint
+  const voterLockPda = (mint: PublicKey, owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("voter_lock"), mint.toBuffer(), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  const lockShares = (mint: PublicKey, owner: Keypair | null, amount: number) => {
+    const ownerKey = owner ? owner.publicKey : wallet.publicKey;
+    return program.methods
+      .lockShares(new BN(amount))
+      .accountsPartial({
+        owner: ownerKey,
+        ftMint: mint,
+        ownerTokenAccount: getAssociatedTokenAddressSync(
+          mint,
+          ownerKey,
+          false,
+          TOKEN_2022_PROGRAM_ID
+        ),
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers(owner ? [owner] : [])
+      .rpc();
+  };
+
   // Test accounts
   let assetKeypair: Keypair;
   let ftMintKeypair: Keypair;
@@ -26,6 +74,9 @@ describe("Voting System Tests", () => {
   let voteStatePda: PublicKey;
   let voteRecordPda: PublicKey;
   let userTokenAccount: PublicKey;
+  let walletLockPda: PublicKey;
+  // Shares the wallet escrows to vote with
+  const lockedAmount = 50_000_000;
 
   before(async () => {
     // Setup: Create a fungible token for voting power
@@ -47,7 +98,7 @@ describe("Voting System Tests", () => {
     );
 
     await program.methods
-      .createFungibleToken(6, 100)
+      .createFungibleToken(6, new BN(100), [], null)
       .accountsPartial({
         payer: wallet.publicKey,
         mint: ftMintKeypair.publicKey,
@@ -71,6 +122,7 @@ describe("Voting System Tests", () => {
     const args = {
       name: "Voting Property NFT",
       uri: "https://example.com/voting-nft.json",
+      attributes: [],
     };
 
     await program.methods
@@ -82,6 +134,9 @@ describe("Voting System Tests", () => {
       })
       .signers([assetKeypair])
       .rpc();
+
+    await lockShares(ftMintKeypair.publicKey, null, lockedAmount);
+    walletLockPda = voterLockPda(ftMintKeypair.publicKey, wallet.publicKey);
   });
 
   describe("Vote Round Creation", () => {
@@ -109,7 +164,7 @@ describe("Voting System Tests", () => {
       );
 
       const tx = await program.methods
-        .createVoteRound(description)
+        .createVoteRound(roundArgs(description))
         .accountsPartial({
           payer: wallet.publicKey,
           tokenAccount: userTokenAccount,
@@ -130,6 +185,9 @@ describe("Voting System Tests", () => {
       assert.equal(voteState.description, description);
       assert.equal(voteState.yesWeight.toNumber(), 0);
       assert.equal(voteState.noWeight.toNumber(), 0);
+      assert.deepEqual(voteState.status, { active: {} });
+      assert.deepEqual(voteState.contentHash, proposalHash(description));
+      assert.include(voteState.metadataUri, "https://example.com/proposals/");
     });
 
     it("Increments round index for subsequent rounds", async () => {
@@ -145,7 +203,7 @@ describe("Voting System Tests", () => {
       );
 
       await program.methods
-        .createVoteRound("Second vote: Property maintenance?")
+        .createVoteRound(roundArgs("Second vote: Property maintenance?"))
         .accountsPartial({
           payer: wallet.publicKey,
           tokenAccount: userTokenAccount,
@@ -158,6 +216,71 @@ describe("Voting System Tests", () => {
       const voteRoundIndex = await program.account.voteRoundIndexState.fetch(voteRoundPda);
       assert.equal(voteRoundIndex.voteRoundCount.toNumber(), 2);
     });
+
+    it("Rejects vote rounds from holders below the proposal threshold", async () => {
+      const smallHolder = Keypair.generate();
+      const fundTx = new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: smallHolder.publicKey,
+          lamports: 0.05 * LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(fundTx);
+
+      const smallHolderTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        ftMintKeypair.publicKey,
+        smallHolder.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // A single base unit is far below the default 1% threshold
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        userTokenAccount,
+        smallHolderTokenAccount.address,
+        wallet.publicKey,
+        1,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const voteRoundIndex = await program.account.voteRoundIndexState.fetch(voteRoundPda);
+      const [spamVoteStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          assetKeypair.publicKey.toBuffer(),
+          smallHolder.publicKey.toBuffer(),
+          voteRoundIndex.voteRoundCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createVoteRound(roundArgs("Spam round"))
+          .accountsPartial({
+            payer: smallHolder.publicKey,
+            tokenAccount: smallHolderTokenAccount.address,
+            assetState: assetStatePda,
+            voteRoundIndex: voteRoundPda,
+            voteState: spamVoteStatePda,
+          })
+          .signers([smallHolder])
+          .rpc();
+
+        assert.fail("Should have failed with ProposalThresholdNotMet");
+      } catch (error) {
+        assert.include(error.message, "ProposalThresholdNotMet");
+      }
+    });
   });
 
   describe("Voting", () => {
@@ -184,7 +307,7 @@ describe("Voting System Tests", () => {
           assetState: assetStatePda,
           voteState: voteStatePda,
           voteRecord: voteRecordPda,
-          voterTokenAccount: userTokenAccount,
+          voterLock: walletLockPda,
         })
         .rpc();
 
@@ -213,7 +336,7 @@ describe("Voting System Tests", () => {
             assetState: assetStatePda,
             voteState: voteStatePda,
             voteRecord: voteRecordPda,
-            voterTokenAccount: userTokenAccount,
+            voterLock: walletLockPda,
           })
           .rpc();
         
@@ -224,6 +347,45 @@ describe("Voting System Tests", () => {
       }
     });
 
+    it("Keeps voted shares locked until the round ends", async () => {
+      const balanceBefore = await getAccount(
+        provider.connection,
+        userTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      try {
+        await program.methods
+          .unlockShares(new BN(lockedAmount))
+          .accountsPartial({
+            owner: wallet.publicKey,
+            ftMint: ftMintKeypair.publicKey,
+            ownerTokenAccount: userTokenAccount,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .rpc();
+
+        assert.fail("Should have failed with SharesStillLocked");
+      } catch (error) {
+        assert.include(error.message, "SharesStillLocked");
+      }
+
+      // Voting shares can't be sent to a fresh wallet to vote a second time
+      const balanceAfter = await getAccount(
+        provider.connection,
+        userTokenAccount,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(balanceAfter.amount.toString(), balanceBefore.amount.toString());
+
+      const voterLock = await program.account.voterLock.fetch(walletLockPda);
+      const voteState = await program.account.voteState.fetch(voteStatePda);
+      assert.equal(voterLock.amount.toNumber(), lockedAmount);
+      assert.equal(voterLock.lockedUntil.toString(), voteState.votingEndTime.toString());
+    });
+
     it("Allows voting AGAINST in a different round", async () => {
       const voteRoundCount = new BN(1);
       const [newVoteStatePda] = PublicKey.findProgramAddressSync(
@@ -257,7 +419,7 @@ describe("Voting System Tests", () => {
           assetState: assetStatePda,
           voteState: newVoteStatePda,
           voteRecord: newVoteRecordPda,
-          voterTokenAccount: userTokenAccount,
+          voterLock: walletLockPda,
         })
         .rpc();
 
@@ -281,7 +443,7 @@ describe("Voting System Tests", () => {
       );
 
       await program.methods
-        .createVoteRound("Third vote: Test invalid choice")
+        .createVoteRound(roundArgs("Third vote: Test invalid choice"))
         .accountsPartial({
           payer: wallet.publicKey,
           tokenAccount: userTokenAccount,
@@ -312,7 +474,7 @@ describe("Voting System Tests", () => {
             assetState: assetStatePda,
             voteState: thirdVoteStatePda,
             voteRecord: tempVoteRecordPda,
-            voterTokenAccount: userTokenAccount,
+            voterLock: walletLockPda,
           })
           .rpc();
         
@@ -322,4 +484,926 @@ describe("Voting System Tests", () => {
       }
     });
   });
+
+  describe("Vote Delegation", () => {
+    const delegatedAmount = 10_000_000;
+    let delegator: Keypair;
+    let delegatorTokenAccount: PublicKey;
+    let delegationPda: PublicKey;
+    let delegatedVoteStatePda: PublicKey;
+    let delegatorVoteRecordPda: PublicKey;
+
+    before(async () => {
+      delegator = Keypair.generate();
+
+      const fundTx = new anchor.web3.Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: delegator.publicKey,
+          lamports: 0.05 * LAMPORTS_PER_SOL,
+        })
+      );
+      await provider.sendAndConfirm(fundTx);
+
+      const delegatorTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        ftMintKeypair.publicKey,
+        delegator.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      delegatorTokenAccount = delegatorTokenAccountInfo.address;
+
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        userTokenAccount,
+        delegatorTokenAccount,
+        wallet.publicKey,
+        delegatedAmount,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await lockShares(ftMintKeypair.publicKey, delegator, delegatedAmount);
+
+      [delegationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_delegation"),
+          assetKeypair.publicKey.toBuffer(),
+          delegator.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      const voteRoundCount = new BN(3);
+      [delegatedVoteStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          assetKeypair.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          voteRoundCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createVoteRound(roundArgs("Fourth vote: Hire a property manager?"))
+        .accountsPartial({
+          payer: wallet.publicKey,
+          tokenAccount: userTokenAccount,
+          assetState: assetStatePda,
+          voteRoundIndex: voteRoundPda,
+          voteState: delegatedVoteStatePda,
+        })
+        .rpc();
+
+      [delegatorVoteRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_record"),
+          delegatedVoteStatePda.toBuffer(),
+          delegator.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+    });
+
+    it("Delegates voting power to another wallet", async () => {
+      await program.methods
+        .delegateVote(wallet.publicKey)
+        .accountsPartial({
+          delegator: delegator.publicKey,
+          asset: assetKeypair.publicKey,
+          assetState: assetStatePda,
+          voteDelegation: delegationPda,
+        })
+        .signers([delegator])
+        .rpc();
+
+      const delegation = await program.account.voteDelegation.fetch(delegationPda);
+      assert.equal(delegation.delegator.toString(), delegator.publicKey.toString());
+      assert.equal(delegation.delegate.toString(), wallet.publicKey.toString());
+    });
+
+    it("Lets the delegate vote with the combined weight", async () => {
+      const [delegateVoteRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_record"),
+          delegatedVoteStatePda.toBuffer(),
+          wallet.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .vote(wallet.publicKey, new BN(3), 1)
+        .accountsPartial({
+          voter: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          assetState: assetStatePda,
+          voteState: delegatedVoteStatePda,
+          voteRecord: delegateVoteRecordPda,
+          voterLock: walletLockPda,
+        })
+        .remainingAccounts([
+          { pubkey: delegationPda, isSigner: false, isWritable: false },
+          {
+            pubkey: voterLockPda(ftMintKeypair.publicKey, delegator.publicKey),
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: delegatorVoteRecordPda, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(delegatedVoteStatePda);
+      assert.equal(voteState.yesWeight.toNumber(), lockedAmount + delegatedAmount);
+
+      // The delegator's escrow stays locked for the round as well
+      const delegatorLock = await program.account.voterLock.fetch(
+        voterLockPda(ftMintKeypair.publicKey, delegator.publicKey)
+      );
+      assert.equal(delegatorLock.lockedUntil.toString(), voteState.votingEndTime.toString());
+
+      const delegatorRecord = await program.account.voteRecord.fetch(delegatorVoteRecordPda);
+      assert.equal(delegatorRecord.voter.toString(), delegator.publicKey.toString());
+      assert.equal(delegatorRecord.castBy.toString(), wallet.publicKey.toString());
+      assert.equal(delegatorRecord.weight.toNumber(), delegatedAmount);
+    });
+
+    it("Lets a delegator override the delegate's vote", async () => {
+      const before = await program.account.voteState.fetch(delegatedVoteStatePda);
+
+      await program.methods
+        .vote(wallet.publicKey, new BN(3), 0)
+        .accountsPartial({
+          voter: delegator.publicKey,
+          asset: assetKeypair.publicKey,
+          assetState: assetStatePda,
+          voteState: delegatedVoteStatePda,
+          voteRecord: delegatorVoteRecordPda,
+          voterLock: voterLockPda(ftMintKeypair.publicKey, delegator.publicKey),
+        })
+        .signers([delegator])
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(delegatedVoteStatePda);
+      assert.equal(
+        voteState.yesWeight.toString(),
+        before.yesWeight.subn(delegatedAmount).toString()
+      );
+      assert.equal(
+        voteState.noWeight.toString(),
+        before.noWeight.addn(delegatedAmount).toString()
+      );
+
+      const delegatorRecord = await program.account.voteRecord.fetch(delegatorVoteRecordPda);
+      assert.equal(delegatorRecord.castBy.toString(), delegator.publicKey.toString());
+      assert.equal(delegatorRecord.choice, 0);
+    });
+
+    it("Prevents a delegator from voting twice", async () => {
+      try {
+        await program.methods
+          .vote(wallet.publicKey, new BN(3), 1)
+          .accountsPartial({
+            voter: delegator.publicKey,
+            asset: assetKeypair.publicKey,
+            assetState: assetStatePda,
+            voteState: delegatedVoteStatePda,
+            voteRecord: delegatorVoteRecordPda,
+            voterLock: voterLockPda(ftMintKeypair.publicKey, delegator.publicKey),
+          })
+          .signers([delegator])
+          .rpc();
+
+        assert.fail("Should have failed with AlreadyVoted");
+      } catch (error) {
+        assert.include(error.message, "AlreadyVoted");
+      }
+    });
+  });
+
+  describe("Proposal Lifecycle", () => {
+    const voteRound = new BN(4);
+    let proposalStatePda: PublicKey;
+    let queuedActionPda: PublicKey;
+
+    before(() => {
+      [proposalStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          assetKeypair.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          voteRound.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      [queuedActionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("queued_action"), proposalStatePda.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Finalizes a passed round with its action once voting ends", async () => {
+      const [proposalRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_record"),
+          proposalStatePda.toBuffer(),
+          wallet.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      const action = {
+        treasuryPayment: { recipient: wallet.publicKey, amount: new BN(1_000_000) },
+      };
+
+      await program.methods
+        .createVoteRound(roundArgs("Pay the roof contractor", 10, action))
+        .accountsPartial({
+          payer: wallet.publicKey,
+          tokenAccount: userTokenAccount,
+          assetState: assetStatePda,
+          voteRoundIndex: voteRoundPda,
+          voteState: proposalStatePda,
+        })
+        .rpc();
+
+      await program.methods
+        .vote(wallet.publicKey, voteRound, 1)
+        .accountsPartial({
+          voter: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          assetState: assetStatePda,
+          voteState: proposalStatePda,
+          voteRecord: proposalRecordPda,
+          voterLock: walletLockPda,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .finalizeVoteRound()
+          .accountsPartial({
+            asset: assetKeypair.publicKey,
+            votingCreator: wallet.publicKey,
+            voteState: proposalStatePda,
+          })
+          .rpc();
+
+        assert.fail("Should have failed while voting is open");
+      } catch (error) {
+        assert.include(error.message, "VotingStillActive");
+      }
+
+      console.log("Waiting 12 seconds for voting to end...");
+      await new Promise((resolve) => setTimeout(resolve, 12000));
+
+      await program.methods
+        .finalizeVoteRound()
+        .accountsPartial({
+          asset: assetKeypair.publicKey,
+          votingCreator: wallet.publicKey,
+          voteState: proposalStatePda,
+        })
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(proposalStatePda);
+      assert.deepEqual(voteState.status, { passed: {} });
+      assert.equal(
+        voteState.action.treasuryPayment.recipient.toString(),
+        wallet.publicKey.toString()
+      );
+    });
+
+    it("Queues the passed proposal behind the timelock", async () => {
+      await program.methods
+        .queueProposal()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: proposalStatePda,
+          queuedAction: queuedActionPda,
+        })
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(proposalStatePda);
+      assert.deepEqual(voteState.status, { queued: {} });
+
+      const queuedAction = await program.account.queuedAction.fetch(queuedActionPda);
+      assert.equal(queuedAction.voteState.toString(), proposalStatePda.toString());
+      assert.equal(
+        queuedAction.eta.sub(queuedAction.queuedAt).toNumber(),
+        2 * 24 * 60 * 60
+      );
+    });
+
+    it("Lets the guardian cancel a queued proposal", async () => {
+      await program.methods
+        .cancelQueuedAction()
+        .accountsPartial({
+          canceler: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: proposalStatePda,
+          queuedAction: queuedActionPda,
+          counterVoteState: null,
+        })
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(proposalStatePda);
+      assert.deepEqual(voteState.status, { canceled: {} });
+    });
+
+    it("Refunds the queued action rent once it was canceled", async () => {
+      await program.methods
+        .closeQueuedAction()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: proposalStatePda,
+          queuedAction: queuedActionPda,
+        })
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(queuedActionPda));
+    });
+
+    it("Lets voters and the creator reclaim rent once the round is over", async () => {
+      const [proposalRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_record"),
+          proposalStatePda.toBuffer(),
+          wallet.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+      const [voteResultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("vote_result"), proposalStatePda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .closeVoteRecord()
+        .accountsPartial({
+          castBy: wallet.publicKey,
+          voteState: proposalStatePda,
+          voteRecord: proposalRecordPda,
+        })
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(proposalRecordPda));
+
+      const closedRound = await program.account.voteState.fetch(proposalStatePda);
+
+      await program.methods
+        .closeVoteRound()
+        .accountsPartial({
+          votingCreator: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          voteState: proposalStatePda,
+          voteResult: voteResultPda,
+        })
+        .rpc();
+
+      assert.isNull(await provider.connection.getAccountInfo(proposalStatePda));
+
+      const voteResult = await program.account.voteResult.fetch(voteResultPda);
+      assert.equal(voteResult.voteRound.toNumber(), voteRound.toNumber());
+      assert.equal(voteResult.yesWeight.toString(), closedRound.yesWeight.toString());
+      assert.deepEqual(voteResult.status, { canceled: {} });
+    });
+  });
+
+  describe("Quadratic Voting", () => {
+    it("Counts votes with the square root of the voter's shares", async () => {
+      const voteRound = new BN(5);
+      const [quadraticVoteStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          assetKeypair.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          voteRound.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [quadraticVoteRecordPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_record"),
+          quadraticVoteStatePda.toBuffer(),
+          wallet.publicKey.toBuffer(),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createVoteRound(roundArgs("Quadratic: Repaint the facade?", 3600, null, { quadratic: {} }))
+        .accountsPartial({
+          payer: wallet.publicKey,
+          tokenAccount: userTokenAccount,
+          assetState: assetStatePda,
+          voteRoundIndex: voteRoundPda,
+          voteState: quadraticVoteStatePda,
+        })
+        .rpc();
+
+      await program.methods
+        .vote(wallet.publicKey, voteRound, 1)
+        .accountsPartial({
+          voter: wallet.publicKey,
+          asset: assetKeypair.publicKey,
+          assetState: assetStatePda,
+          voteState: quadraticVoteStatePda,
+          voteRecord: quadraticVoteRecordPda,
+          voterLock: walletLockPda,
+        })
+        .rpc();
+
+      const rawWeight = lockedAmount;
+      const effectiveWeight = Math.floor(Math.sqrt(rawWeight));
+
+      const voteRecord = await program.account.voteRecord.fetch(quadraticVoteRecordPda);
+      assert.equal(voteRecord.rawWeight.toNumber(), rawWeight);
+      assert.equal(voteRecord.weight.toNumber(), effectiveWeight);
+
+      const voteState = await program.account.voteState.fetch(quadraticVoteStatePda);
+      assert.deepEqual(voteState.votingMode, { quadratic: {} });
+      assert.equal(voteState.yesWeight.toNumber(), effectiveWeight);
+      assert.equal(voteState.turnout.toNumber(), rawWeight);
+    });
+  });
+
+  describe("Proposal Threshold", () => {
+    it("Counts locked shares towards the proposal threshold", async () => {
+      const lockedHolder = Keypair.generate();
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: wallet.publicKey,
+            toPubkey: lockedHolder.publicKey,
+            lamports: 0.05 * LAMPORTS_PER_SOL,
+          })
+        )
+      );
+
+      const lockedHolderTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        wallet.payer,
+        ftMintKeypair.publicKey,
+        lockedHolder.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // 2% of the supply, all of it escrowed in the holder's voter lock
+      await transfer(
+        provider.connection,
+        wallet.payer,
+        userTokenAccount,
+        lockedHolderTokenAccount.address,
+        wallet.publicKey,
+        2_000_000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await lockShares(ftMintKeypair.publicKey, lockedHolder, 2_000_000);
+
+      const voteRoundIndex = await program.account.voteRoundIndexState.fetch(voteRoundPda);
+      const [lockedVoteStatePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          assetKeypair.publicKey.toBuffer(),
+          lockedHolder.publicKey.toBuffer(),
+          voteRoundIndex.voteRoundCount.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createVoteRound(roundArgs("Round from locked shares"))
+        .accountsPartial({
+          payer: lockedHolder.publicKey,
+          tokenAccount: lockedHolderTokenAccount.address,
+          assetState: assetStatePda,
+          voteRoundIndex: voteRoundPda,
+          voteState: lockedVoteStatePda,
+        })
+        .signers([lockedHolder])
+        .rpc();
+
+      const voteState = await program.account.voteState.fetch(lockedVoteStatePda);
+      assert.equal(voteState.votingCreator.toString(), lockedHolder.publicKey.toString());
+    });
+  });
+
+  describe("Share Split", () => {
+    const splitAsset = Keypair.generate();
+    const oldMint = Keypair.generate();
+    const newMint = Keypair.generate();
+    const voteRound = new BN(0);
+    let splitAssetState: PublicKey;
+    let splitVoteState: PublicKey;
+    let splitQueuedAction: PublicKey;
+
+    const holderAccount = (mint: PublicKey) =>
+      getAssociatedTokenAddressSync(mint, wallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    before(async () => {
+      await program.methods
+        .createFungibleToken(6, new BN(10), [], null)
+        .accountsPartial({
+          payer: wallet.publicKey,
+          mint: oldMint.publicKey,
+          tokenAccount: holderAccount(oldMint.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([oldMint])
+        .rpc();
+
+      await program.methods
+        .createNonFungibleToken({
+          name: "Split Property NFT",
+          uri: "https://example.com/split-nft.json",
+          attributes: [],
+          // No timelock so the split can execute right after the vote
+          timelockDelay: new BN(0),
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: splitAsset.publicKey,
+          ftMint: oldMint.publicKey,
+        })
+        .signers([splitAsset])
+        .rpc();
+
+      await lockShares(oldMint.publicKey, null, 9_000_000);
+
+
+      [splitAssetState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), splitAsset.publicKey.toBuffer()],
+        program.programId
+      );
+      [splitVoteState] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          splitAsset.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          voteRound.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [splitQueuedAction] = PublicKey.findProgramAddressSync(
+        [Buffer.from("queued_action"), splitVoteState.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Executes a passed 10:1 split into a new share mint", async () => {
+      await program.methods
+        .createVoteRound(
+          roundArgs("Split every share into ten", 10, {
+            shareSplit: { numerator: 10, denominator: 1 },
+          })
+        )
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: splitAsset.publicKey,
+          ftMint: oldMint.publicKey,
+          tokenAccount: holderAccount(oldMint.publicKey),
+          assetState: splitAssetState,
+          voteState: splitVoteState,
+        })
+        .rpc();
+
+      await program.methods
+        .vote(wallet.publicKey, voteRound, 1)
+        .accountsPartial({
+          voter: wallet.publicKey,
+          asset: splitAsset.publicKey,
+          ftMint: oldMint.publicKey,
+          assetState: splitAssetState,
+          voteState: splitVoteState,
+          voterLock: voterLockPda(oldMint.publicKey, wallet.publicKey),
+        })
+        .rpc();
+
+      console.log("Waiting 12 seconds for voting to end...");
+      await new Promise((resolve) => setTimeout(resolve, 12000));
+
+      await program.methods
+        .finalizeVoteRound()
+        .accountsPartial({
+          asset: splitAsset.publicKey,
+          votingCreator: wallet.publicKey,
+          voteState: splitVoteState,
+        })
+        .rpc();
+
+      // The round is over, so the voting shares can leave the escrow before migrating
+      await program.methods
+        .unlockShares(new BN(9_000_000))
+        .accountsPartial({
+          owner: wallet.publicKey,
+          ftMint: oldMint.publicKey,
+          ownerTokenAccount: holderAccount(oldMint.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      await program.methods
+        .queueProposal()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: splitAsset.publicKey,
+          voteState: splitVoteState,
+          queuedAction: splitQueuedAction,
+        })
+        .rpc();
+
+      await program.methods
+        .executeShareSplit()
+        .accountsPartial({
+          executor: wallet.publicKey,
+          asset: splitAsset.publicKey,
+          ftMint: oldMint.publicKey,
+          newMint: newMint.publicKey,
+          voteState: splitVoteState,
+          queuedAction: splitQueuedAction,
+          // The shares carry no transfer hook
+          extraAccountMetaList: null,
+          complianceConfig: null,
+          complianceHookProgram: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([newMint])
+        .rpc();
+
+      // The supply already counts the new shares holders are still to migrate
+      const assetState = await program.account.assetState.fetch(splitAssetState);
+      assert.equal(assetState.ftMint.toString(), newMint.publicKey.toString());
+      assert.equal(assetState.totalShares.toString(), "100000000");
+      assert.isTrue(assetState.shareSplitInProgress);
+    });
+
+    it("Migrates a holder to the new mint", async () => {
+      await program.methods
+        .claimShareSplit()
+        .accountsPartial({
+          holder: wallet.publicKey,
+          oldMint: oldMint.publicKey,
+          newMint: newMint.publicKey,
+          holderOldAccount: holderAccount(oldMint.publicKey),
+          holderNewAccount: holderAccount(newMint.publicKey),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const newShares = await getAccount(
+        provider.connection,
+        holderAccount(newMint.publicKey),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(newShares.amount.toString(), "100000000");
+
+      const oldMintInfo = await getMint(
+        provider.connection,
+        oldMint.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(oldMintInfo.supply.toString(), "0");
+
+      const assetState = await program.account.assetState.fetch(splitAssetState);
+      assert.equal(assetState.totalShares.toString(), "100000000");
+    });
+
+    it("Blocks new vote rounds until the split finishes", async () => {
+      const nextRound = new BN(1);
+      const [nextVoteState] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          splitAsset.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          nextRound.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createVoteRound(roundArgs("Round during the split"))
+          .accountsPartial({
+            payer: wallet.publicKey,
+            asset: splitAsset.publicKey,
+            ftMint: newMint.publicKey,
+            tokenAccount: holderAccount(newMint.publicKey),
+            assetState: splitAssetState,
+            voteState: nextVoteState,
+          })
+          .rpc();
+        assert.fail("Vote round creation should have failed during the split");
+      } catch (error) {
+        assert.include(error.toString(), "ShareSplitInProgress");
+      }
+    });
+
+    it("Finishes the split once every old share migrated", async () => {
+      const [shareSplit] = PublicKey.findProgramAddressSync(
+        [Buffer.from("share_split"), oldMint.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .finishShareSplit()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          shareSplit,
+          assetState: splitAssetState,
+          newMint: newMint.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      // A 10:1 split leaves no dust, and the new supply is fixed
+      const assetState = await program.account.assetState.fetch(splitAssetState);
+      assert.equal(assetState.totalShares.toString(), "100000000");
+      assert.isFalse(assetState.shareSplitInProgress);
+
+      const newMintInfo = await getMint(
+        provider.connection,
+        newMint.publicKey,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isNull(newMintInfo.mintAuthority);
+    });
+  });
+
+  describe("Config Updates", () => {
+    const configAsset = Keypair.generate();
+    const configMint = Keypair.generate();
+    const voteRound = new BN(0);
+    let configAssetState: PublicKey;
+    let configVoteState: PublicKey;
+    let configQueuedAction: PublicKey;
+
+    before(async () => {
+      await program.methods
+        .createFungibleToken(6, new BN(10), [], null)
+        .accountsPartial({
+          payer: wallet.publicKey,
+          mint: configMint.publicKey,
+          tokenAccount: getAssociatedTokenAddressSync(
+            configMint.publicKey,
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([configMint])
+        .rpc();
+
+      await program.methods
+        .createNonFungibleToken({
+          name: "Config Property NFT",
+          uri: "https://example.com/config-nft.json",
+          attributes: [],
+          timelockDelay: new BN(0),
+        })
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: configAsset.publicKey,
+          ftMint: configMint.publicKey,
+        })
+        .signers([configAsset])
+        .rpc();
+
+      await lockShares(configMint.publicKey, null, 9_000_000);
+
+      [configAssetState] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_state"), configAsset.publicKey.toBuffer()],
+        program.programId
+      );
+      [configVoteState] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("vote_state"),
+          configAsset.publicKey.toBuffer(),
+          wallet.publicKey.toBuffer(),
+          voteRound.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [configQueuedAction] = PublicKey.findProgramAddressSync(
+        [Buffer.from("queued_action"), configVoteState.toBuffer()],
+        program.programId
+      );
+    });
+
+    it("Holds a passed config update for the minimum delay", async () => {
+      const newGuardian = Keypair.generate().publicKey;
+
+      await program.methods
+        .createVoteRound(
+          roundArgs("Replace the guardian", 10, {
+            updateConfig: {
+              timelockDelay: null,
+              guardian: newGuardian,
+              supermajorityBps: null,
+              proposalThresholdBps: null,
+              proposalDeposit: null,
+              quorumBps: null,
+              tagAlongThresholdBps: null,
+              tagAlongWindow: null,
+            },
+          })
+        )
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: configAsset.publicKey,
+          ftMint: configMint.publicKey,
+          tokenAccount: getAssociatedTokenAddressSync(
+            configMint.publicKey,
+            wallet.publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          ),
+          assetState: configAssetState,
+          voteState: configVoteState,
+        })
+        .rpc();
+
+      await program.methods
+        .vote(wallet.publicKey, voteRound, 1)
+        .accountsPartial({
+          voter: wallet.publicKey,
+          asset: configAsset.publicKey,
+          ftMint: configMint.publicKey,
+          assetState: configAssetState,
+          voteState: configVoteState,
+          voterLock: voterLockPda(configMint.publicKey, wallet.publicKey),
+        })
+        .rpc();
+
+      console.log("Waiting 12 seconds for voting to end...");
+      await new Promise((resolve) => setTimeout(resolve, 12000));
+
+      await program.methods
+        .finalizeVoteRound()
+        .accountsPartial({
+          asset: configAsset.publicKey,
+          votingCreator: wallet.publicKey,
+          voteState: configVoteState,
+        })
+        .rpc();
+
+      await program.methods
+        .queueProposal()
+        .accountsPartial({
+          payer: wallet.publicKey,
+          asset: configAsset.publicKey,
+          voteState: configVoteState,
+          queuedAction: configQueuedAction,
+        })
+        .rpc();
+
+      // The asset has no timelock, but governance changes still wait two days
+      const queuedAction = await program.account.queuedAction.fetch(configQueuedAction);
+      assert.equal(
+        queuedAction.eta.sub(queuedAction.queuedAt).toNumber(),
+        2 * 24 * 60 * 60
+      );
+
+      try {
+        await program.methods
+          .executeConfigUpdate()
+          .accountsPartial({
+            executor: wallet.publicKey,
+            asset: configAsset.publicKey,
+            voteState: configVoteState,
+            queuedAction: configQueuedAction,
+          })
+          .rpc();
+
+        assert.fail("Should have failed with TimelockNotExpired");
+      } catch (error) {
+        assert.include(error.message, "TimelockNotExpired");
+      }
+
+      const [assetConfigPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("asset_config"), configAsset.publicKey.toBuffer()],
+        program.programId
+      );
+      const assetConfig = await program.account.assetConfig.fetch(assetConfigPda);
+      assert.equal(assetConfig.guardian.toString(), wallet.publicKey.toString());
+    });
+  });
 });